@@ -0,0 +1,161 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use futures_util::StreamExt;
+
+use crate::node::{Node, Result};
+use crate::types::MultiChanBackup;
+
+/// The prefix of the backup file names.
+const PREFIX: &str = "channel-";
+
+/// The extension of the backup file names, matching the `channel.backup` file written by the node.
+const EXTENSION: &str = ".backup";
+
+/// Write the multi-channel backups of a node to a local directory, keeping the most recent ones.
+///
+/// Each backup is written to a temporary file that is renamed once synced to disk, so a crash never leaves a
+/// truncated backup behind. Backups are named after the time they were written, e.g.
+/// `channel-00000001690000000000000000.backup`, so the latest one is last in alphabetical order.
+///
+/// ```rust,no_run
+/// use lnd_rest::backup::BackupWriter;
+/// use lnd_rest::node::Node;
+///
+/// # async fn run(node: Node) -> lnd_rest::node::Result<()> {
+/// let writer = BackupWriter::new("/mnt/backups/lnd", 10);
+///
+/// // Write the current backup, then a new one each time a channel is opened or closed
+/// writer.run(&node).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BackupWriter {
+    dir: PathBuf,
+    keep: usize,
+}
+
+impl BackupWriter {
+    /// Create a [BackupWriter] writing to the given directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory to write the backups to, which must exist.
+    /// * `keep` - The number of backups to keep, the older ones being deleted. At least one backup is kept.
+    ///
+    pub fn new<P: AsRef<Path>>(dir: P, keep: usize) -> Self {
+        BackupWriter {
+            dir: dir.as_ref().to_path_buf(),
+            keep: keep.max(1),
+        }
+    }
+
+    /// Write the current multi-channel backup of the node, then each new one until the subscription is closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node to retrieve the backups from.
+    ///
+    pub async fn run(&self, node: &Node) -> Result<()> {
+        // Subscribe first, so that no backup is missed while the current one is being written.
+        let mut snapshots = node.subscribe_channel_backups().await?;
+
+        let snapshot = node.export_all_channel_backups().await?;
+        if let Some(multi_chan_backup) = snapshot.multi_chan_backup {
+            self.write_in_background(multi_chan_backup).await?;
+        }
+
+        while let Some(snapshot) = snapshots.next().await {
+            if let Some(multi_chan_backup) = snapshot?.multi_chan_backup {
+                self.write_in_background(multi_chan_backup).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write a multi-channel backup on a thread allowed to block, so that syncing the files to disk does not stall the
+    /// runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_chan_backup` - The multi-channel backup to write.
+    ///
+    async fn write_in_background(&self, multi_chan_backup: MultiChanBackup) -> Result<PathBuf> {
+        let writer = self.clone();
+
+        tokio::task::spawn_blocking(move || writer.write(&multi_chan_backup))
+            .await
+            .map_err(std::io::Error::from)?
+    }
+
+    /// Write a multi-channel backup to the directory and delete the oldest backups, returning the path of the new file.
+    ///
+    /// The file is synced to disk before returning, which blocks the calling thread: async callers should run it with
+    /// `tokio::task::spawn_blocking`, as [BackupWriter::run] does.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_chan_backup` - A reference to the multi-channel backup to write.
+    ///
+    pub fn write(&self, multi_chan_backup: &MultiChanBackup) -> Result<PathBuf> {
+        let data = BASE64_STANDARD.decode(&multi_chan_backup.multi_chan_backup)?;
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let name = format!("{PREFIX}{nanos:026}{EXTENSION}");
+        let path = self.dir.join(&name);
+        let tmp_path = self.dir.join(format!(".{name}.tmp"));
+
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(&data)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, &path)?;
+
+        // Persist the rename itself, which is not possible on every platform.
+        #[cfg(unix)]
+        File::open(&self.dir)?.sync_all()?;
+
+        self.rotate()?;
+
+        Ok(path)
+    }
+
+    /// Return the paths of the backups in the directory, from the oldest to the latest.
+    pub fn backups(&self) -> Result<Vec<PathBuf>> {
+        let mut backups = Vec::new();
+
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let is_backup = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(PREFIX) && name.ends_with(EXTENSION));
+            if is_backup {
+                backups.push(path);
+            }
+        }
+
+        backups.sort();
+
+        Ok(backups)
+    }
+
+    /// Delete the oldest backups, keeping only the configured number of backups.
+    fn rotate(&self) -> Result<()> {
+        let backups = self.backups()?;
+        let excess = backups.len().saturating_sub(self.keep);
+
+        for path in &backups[..excess] {
+            fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1644 @@
+use std::collections::VecDeque;
+use std::path::Path;
+
+use reqwest::blocking::Client;
+
+use crate::node::{
+    macaroon_headers, read_certificate, socks_proxy, url_safe_base64, NodeError, NodeOptions,
+    Result,
+};
+use crate::stream::{BlockingStream, RpcError};
+use crate::types::{
+    AddInvoiceRequest, AddInvoiceResponse, BuildRouteRequest, BuildRouteResponse, BumpFeeRequest,
+    ChanBackupSnapshot, ChangePasswordRequest, ChangePasswordResponse, ChannelBackup, ChannelEdge,
+    ChannelEventUpdate, ChannelGraph, ClosedChannelsRequest, ClosedChannelsResponse,
+    ConnectPeerRequest, EstimateFeeRequest, EstimateFeeResponse, FeeReportResponse, FeeTier,
+    FinalizePsbtRequest, FinalizePsbtResponse, ForwardingEvent, ForwardingHistoryRequest,
+    ForwardingHistoryResponse, FundPsbtRequest, FundPsbtResponse, FundingTransitionMsg,
+    GenSeedRequest, GenSeedResponse, GetInfoResponse, GetMissionControlConfigResponse,
+    GetStateResponse, GetTransactionsRequest, GraphTopologyUpdate, HTLCAttempt, HtlcEvent,
+    ImportAccountRequest, ImportAccountResponse, ImportPublicKeyRequest, InitWalletRequest,
+    InitWalletResponse, KeyDescriptor, KeyLocator, KeyReq, LeaseOutputRequest, LeaseOutputResponse,
+    ListAccountsRequest, ListAccountsResponse, ListAddressesRequest, ListAddressesResponse,
+    ListChannelsRequest, ListChannelsResponse, ListLeasesResponse, ListPaymentsRequest,
+    ListPaymentsResponse, ListPeersResponse, ListSweepsRequest, ListSweepsResponse,
+    ListUnspentRequest, ListUnspentResponse, LookupInvoiceResponse, NetworkInfo, NewAddressRequest,
+    NewAddressResponse, NodeInfo, NodeMetricsResponse, OpenChannelRequest, OpenStatusUpdate,
+    PeerEvent, PendingSweepsResponse, PolicyUpdateRequest, PolicyUpdateResponse, PublishResponse,
+    PublishTransactionRequest, QueryMissionControlResponse, QueryProbabilityResponse,
+    QueryRoutesRequest, QueryRoutesResponse, ReleaseOutputRequest, RestoreChanBackupRequest,
+    SendCoinsRequest, SendCoinsResponse, SendManyRequest, SendManyResponse, SendPaymentSyncRequest,
+    SendPaymentSyncResponse, SendToRouteRequest, SetMissionControlConfigRequest, SharedKeyRequest,
+    SharedKeyResponse, SignMessageReq, SignMessageRequest, SignMessageResp, SignMessageResponse,
+    SignPsbtRequest, SignPsbtResponse, SubscribeStateResponse, Transaction, TransactionDetails,
+    UnlockWalletRequest, VerifyMessageReq, VerifyMessageRequest, VerifyMessageResp,
+    VerifyMessageResponse, WalletEstimateFeeResponse, WalletState, XImportMissionControlRequest,
+};
+
+/// Encapsulate data needed to interact with a Lightning Network Daemon (LND) node from synchronous code.
+///
+/// This is the blocking counterpart of [Node](crate::node::Node): it exposes the same methods, taking and returning
+/// the same types, but each call blocks the current thread until the node answers. It must not be used from within
+/// an async runtime.
+#[derive(Clone, Debug)]
+pub struct BlockingNode {
+    /// The host address of the LND node.
+    pub host: String,
+    /// The blocking HTTP client used to communicate with the LND node.
+    pub client: Client,
+}
+
+impl BlockingNode {
+    /// Initialize a [BlockingNode] object using the macaroon and certificate files provided.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host address to connect to.
+    /// * `macaroon_path` - The path to the macaroon file.
+    /// * `cert_path` - The path to the certificate file.
+    ///
+    pub fn init<P: AsRef<Path>>(host: String, macaroon_path: P, cert_path: P) -> Result<Self> {
+        Self::init_with_options(host, macaroon_path, cert_path, &NodeOptions::default())
+    }
+
+    /// Initialize a [BlockingNode] object using the macaroon and certificate files provided, and the given options.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host address to connect to.
+    /// * `macaroon_path` - The path to the macaroon file.
+    /// * `cert_path` - The path to the certificate file.
+    /// * `options` - A reference to a [NodeOptions] object containing the optional settings.
+    ///
+    pub fn init_with_options<P: AsRef<Path>>(
+        host: String,
+        macaroon_path: P,
+        cert_path: P,
+        options: &NodeOptions,
+    ) -> Result<Self> {
+        let headers = macaroon_headers(macaroon_path)?;
+
+        Self::init_with_headers(host, headers, cert_path, options)
+    }
+
+    /// Initialize a [BlockingNode] object sending no macaroon, using the certificate file provided and the given options.
+    ///
+    /// Such a node can only call the methods that do not require a macaroon: [BlockingNode::gen_seed], [BlockingNode::init_wallet],
+    /// [BlockingNode::unlock_wallet], [BlockingNode::change_password], [BlockingNode::get_state] and [BlockingNode::subscribe_state]. This lets a
+    /// wallet be created or unlocked before a macaroon exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host address to connect to.
+    /// * `cert_path` - The path to the certificate file.
+    /// * `options` - A reference to a [NodeOptions] object containing the optional settings.
+    ///
+    pub fn init_without_macaroon<P: AsRef<Path>>(
+        host: String,
+        cert_path: P,
+        options: &NodeOptions,
+    ) -> Result<Self> {
+        Self::init_with_headers(host, reqwest::header::HeaderMap::new(), cert_path, options)
+    }
+
+    /// Build the HTTP client sending the given headers with every request.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host address to connect to.
+    /// * `headers` - The headers to send with every request.
+    /// * `cert_path` - The path to the certificate file.
+    /// * `options` - A reference to a [NodeOptions] object containing the optional settings.
+    ///
+    fn init_with_headers<P: AsRef<Path>>(
+        host: String,
+        headers: reqwest::header::HeaderMap,
+        cert_path: P,
+        options: &NodeOptions,
+    ) -> Result<Self> {
+        // Unlike the async client, the blocking client times out after 30 seconds by default, which would end idle
+        // subscriptions and long calls such as payments.
+        let mut builder = Client::builder().timeout(None).default_headers(headers);
+
+        match options.pinned_tls_config()? {
+            #[cfg(feature = "rustls")]
+            Some(tls) => builder = builder.use_preconfigured_tls(tls),
+            _ => builder = builder.add_root_certificate(read_certificate(cert_path)?),
+        }
+
+        if let Some(proxy) = &options.proxy {
+            builder = builder.proxy(socks_proxy(proxy)?);
+        }
+
+        let client = builder.build()?;
+
+        Ok(BlockingNode { host, client })
+    }
+
+    /// Handle the response from an HTTP request and perform error checking based on the response status code.
+    ///
+    /// Errors described by the node in the response body are returned as [NodeError::ApiError](crate::node::NodeError::ApiError).
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - An object representing the HTTP response.
+    ///
+    fn on_response(response: reqwest::blocking::Response) -> Result<reqwest::blocking::Response> {
+        let status = response.status();
+
+        match status {
+            reqwest::StatusCode::OK => Ok(response),
+            _ => match response.error_for_status_ref() {
+                Ok(_) => Ok(response),
+                Err(err) => {
+                    // LND describes the error in the body, which is more helpful than the status code alone.
+                    let body = response.bytes()?;
+
+                    match serde_json::from_slice::<RpcError>(&body) {
+                        Ok(error) => Err(error.into()),
+                        Err(_) => Err(err.into()),
+                    }
+                }
+            },
+        }
+    }
+
+    /// Send a POST request to add a new invoice.
+    ///
+    /// # Arguments
+    ///
+    /// * `invoice` - A reference to a [AddInvoiceRequest] object containing the details of the invoice to be added.
+    ///
+    pub fn add_invoice(&self, invoice: &AddInvoiceRequest) -> Result<AddInvoiceResponse> {
+        let url = format!("{host}/v1/invoices", host = self.host);
+
+        let mut response = self.client.post(&url).json(invoice).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: AddInvoiceResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve information about an invoice.
+    ///
+    /// # Arguments
+    ///
+    /// * `payment_hash` - A reference to the payment hash of the invoice to lookup.
+    ///
+    pub fn lookup_invoice(&self, payment_hash: &String) -> Result<LookupInvoiceResponse> {
+        let url = format!(
+            "{host}/v1/invoice/{payment_hash}",
+            host = self.host,
+            payment_hash = payment_hash
+        );
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: LookupInvoiceResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to initiate a payment for a given payment request.
+    ///
+    /// # Arguments
+    ///
+    /// * `payment_request` - A reference to a [SendPaymentSyncRequest] object containing the details of the payment request.
+    ///
+    pub fn pay_invoice(
+        &self,
+        payment_request: &SendPaymentSyncRequest,
+    ) -> Result<SendPaymentSyncResponse> {
+        let url = format!("{host}/v1/channels/transactions", host = self.host);
+
+        let mut response = self.client.post(&url).json(payment_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: SendPaymentSyncResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve a list of all outgoing payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_payments_request` - A reference to a [ListPaymentsRequest] object containing the parameters of the request.
+    ///
+    pub fn list_payments(
+        &self,
+        list_payments_request: &ListPaymentsRequest,
+    ) -> Result<ListPaymentsResponse> {
+        let url = format!(
+            "{host}/v1/payments?include_incomplete={include_incomplete}&index_offset={index_offset}&max_payments={max_payments}&reversed={reversed}&count_total_payments={count_total_payments}&creation_date_start={creation_date_start}&creation_date_end={creation_date_end}",
+            host = self.host,
+            include_incomplete = list_payments_request.include_incomplete,
+            index_offset = list_payments_request.index_offset,
+            max_payments = list_payments_request.max_payments,
+            reversed = list_payments_request.reversed,
+            count_total_payments = list_payments_request.count_total_payments,
+            creation_date_start = list_payments_request.creation_date_start,
+            creation_date_end = list_payments_request.creation_date_end
+        );
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ListPaymentsResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve general information about the node.
+    pub fn get_info(&self) -> Result<GetInfoResponse> {
+        let url = format!("{host}/v1/getinfo", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: GetInfoResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the list of connected peers.
+    ///
+    /// # Arguments
+    ///
+    /// * `latest_error` - Whether to only return the latest error of each peer, instead of all recorded errors.
+    ///
+    pub fn list_peers(&self, latest_error: bool) -> Result<ListPeersResponse> {
+        let url = format!(
+            "{host}/v1/peers?latest_error={latest_error}",
+            host = self.host,
+            latest_error = latest_error
+        );
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ListPeersResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to connect to a peer.
+    ///
+    /// # Arguments
+    ///
+    /// * `connect_peer_request` - A reference to a [ConnectPeerRequest] object containing the address of the peer.
+    ///
+    pub fn connect_peer(&self, connect_peer_request: &ConnectPeerRequest) -> Result<()> {
+        let url = format!("{host}/v1/peers", host = self.host);
+
+        let response = self.client.post(&url).json(connect_peer_request).send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a DELETE request to disconnect from a peer.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - A reference to the hex encoded public key of the peer.
+    ///
+    pub fn disconnect_peer(&self, pub_key: &String) -> Result<()> {
+        let url = format!(
+            "{host}/v1/peers/{pub_key}",
+            host = self.host,
+            pub_key = pub_key
+        );
+
+        let response = self.client.delete(&url).send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to subscribe to peers going online and offline.
+    pub fn subscribe_peer_events(&self) -> Result<BlockingStream<PeerEvent>> {
+        let url = format!("{host}/v1/peers/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        Ok(BlockingStream::new(response))
+    }
+
+    /// Send a GET request to generate a new on-chain address.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_address_request` - A reference to a [NewAddressRequest] object containing the address type and account.
+    ///
+    pub fn new_address(
+        &self,
+        new_address_request: &NewAddressRequest,
+    ) -> Result<NewAddressResponse> {
+        let url = format!("{host}/v1/newaddress", host = self.host);
+
+        let mut response = self.client.get(&url).query(new_address_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: NewAddressResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to send coins on-chain to a single address.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_coins_request` - A reference to a [SendCoinsRequest] object containing the details of the transaction.
+    ///
+    pub fn send_coins(&self, send_coins_request: &SendCoinsRequest) -> Result<SendCoinsResponse> {
+        let url = format!("{host}/v1/transactions", host = self.host);
+
+        let mut response = self.client.post(&url).json(send_coins_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: SendCoinsResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to send coins on-chain to several addresses in a single transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_many_request` - A reference to a [SendManyRequest] object containing the details of the transaction.
+    ///
+    pub fn send_many(&self, send_many_request: &SendManyRequest) -> Result<SendManyResponse> {
+        let url = format!("{host}/v1/transactions/many", host = self.host);
+
+        let mut response = self.client.post(&url).json(send_many_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: SendManyResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the unspent outputs of the on-chain wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_unspent_request` - A reference to a [ListUnspentRequest] object containing the confirmation range and account.
+    ///
+    pub fn list_unspent(
+        &self,
+        list_unspent_request: &ListUnspentRequest,
+    ) -> Result<ListUnspentResponse> {
+        let url = format!("{host}/v1/utxos", host = self.host);
+
+        let mut response = self.client.get(&url).query(list_unspent_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ListUnspentResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the on-chain transactions relevant to the wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `get_transactions_request` - A reference to a [GetTransactionsRequest] object containing the block range and account.
+    ///
+    pub fn get_transactions(
+        &self,
+        get_transactions_request: &GetTransactionsRequest,
+    ) -> Result<TransactionDetails> {
+        let url = format!("{host}/v1/transactions", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(get_transactions_request)
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: TransactionDetails = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to estimate the fee of an on-chain transaction to the given outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `estimate_fee_request` - A reference to a [EstimateFeeRequest] object containing the outputs and confirmation target.
+    ///
+    pub fn estimate_fee(
+        &self,
+        estimate_fee_request: &EstimateFeeRequest,
+    ) -> Result<EstimateFeeResponse> {
+        let url = format!("{host}/v1/transactions/fee", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&estimate_fee_request.query())
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: EstimateFeeResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to estimate the fee rate needed to confirm a transaction within the given number of blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `conf_target` - The number of blocks within which the transaction should confirm, at least 2.
+    ///
+    pub fn estimate_fee_rate(&self, conf_target: u32) -> Result<WalletEstimateFeeResponse> {
+        let url = format!(
+            "{host}/v2/wallet/estimatefee/{conf_target}",
+            host = self.host,
+            conf_target = conf_target
+        );
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: WalletEstimateFeeResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Quote the fee rate of a tier, in satoshis per virtual byte, ready to be used as `sat_per_vbyte` when sending coins.
+    ///
+    /// # Arguments
+    ///
+    /// * `tier` - The [FeeTier] to quote.
+    ///
+    pub fn fee_rate(&self, tier: FeeTier) -> Result<u64> {
+        self.estimate_fee_rate(tier.conf_target())?.sat_per_vbyte()
+    }
+
+    /// Send a GET request to subscribe to on-chain transactions relevant to the wallet.
+    ///
+    /// The node sends a [Transaction] when it first sees it, unconfirmed, and again when it is first confirmed. Further
+    /// confirmations are not notified: once a transaction is confirmed, its confirmation depth can be derived from the
+    /// current block height.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The wallet account to filter transactions on, or `None` for all accounts.
+    ///
+    pub fn subscribe_transactions(
+        &self,
+        account: Option<&str>,
+    ) -> Result<BlockingStream<Transaction>> {
+        let url = format!("{host}/v1/transactions/subscribe", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&[("account", account)])
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        Ok(BlockingStream::new(response))
+    }
+
+    /// Send a GET request to list the open channels of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_channels_request` - A reference to a [ListChannelsRequest] object containing the filters to apply.
+    ///
+    pub fn list_channels(
+        &self,
+        list_channels_request: &ListChannelsRequest,
+    ) -> Result<ListChannelsResponse> {
+        let url = format!("{host}/v1/channels", host = self.host);
+
+        let mut response = self.client.get(&url).query(list_channels_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ListChannelsResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to list the closed channels of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `closed_channels_request` - A reference to a [ClosedChannelsRequest] object containing the kinds of closure to include, all of them if none is set.
+    ///
+    pub fn closed_channels(
+        &self,
+        closed_channels_request: &ClosedChannelsRequest,
+    ) -> Result<ClosedChannelsResponse> {
+        let url = format!("{host}/v1/channels/closed", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(closed_channels_request)
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ClosedChannelsResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to subscribe to channels being opened, closed, activated and deactivated.
+    pub fn subscribe_channel_events(&self) -> Result<BlockingStream<ChannelEventUpdate>> {
+        let url = format!("{host}/v1/channels/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        Ok(BlockingStream::new(response))
+    }
+
+    /// Send a GET request to retrieve the channel graph known to the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_unannounced` - Whether to include the private channels and the channels not yet announced.
+    ///
+    pub fn describe_graph(&self, include_unannounced: bool) -> Result<ChannelGraph> {
+        let url = format!("{host}/v1/graph", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&[("include_unannounced", include_unannounced)])
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ChannelGraph = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve information about a node of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - A reference to the hex encoded public key of the node.
+    /// * `include_channels` - Whether to include the channels of the node.
+    ///
+    pub fn get_node_info(&self, pub_key: &String, include_channels: bool) -> Result<NodeInfo> {
+        let url = format!(
+            "{host}/v1/graph/node/{pub_key}",
+            host = self.host,
+            pub_key = pub_key
+        );
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&[("include_channels", include_channels)])
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: NodeInfo = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve information about a channel of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `chan_id` - A reference to the short channel id of the channel.
+    ///
+    pub fn get_chan_info(&self, chan_id: &String) -> Result<ChannelEdge> {
+        let url = format!(
+            "{host}/v1/graph/edge/{chan_id}",
+            host = self.host,
+            chan_id = chan_id
+        );
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ChannelEdge = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve statistics about the channel graph.
+    pub fn get_network_info(&self) -> Result<NetworkInfo> {
+        let url = format!("{host}/v1/graph/info", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: NetworkInfo = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the betweenness centrality of every node of the graph.
+    pub fn get_node_metrics(&self) -> Result<NodeMetricsResponse> {
+        let url = format!("{host}/v1/graph/nodemetrics", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&[("types", "BETWEENNESS_CENTRALITY")])
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: NodeMetricsResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to subscribe to changes of the channel graph.
+    pub fn subscribe_channel_graph(&self) -> Result<BlockingStream<GraphTopologyUpdate>> {
+        let url = format!("{host}/v1/graph/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        Ok(BlockingStream::new(response))
+    }
+
+    /// Send a GET request to find routes paying a given amount to a destination node.
+    ///
+    /// Ignored pairs and route hints cannot be encoded as query parameters, so requests holding them are sent as a
+    /// POST request to `/v1/graph/routes` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_routes_request` - A reference to a [QueryRoutesRequest] object containing the destination, the amount and the constraints on the routes.
+    ///
+    pub fn query_routes(
+        &self,
+        query_routes_request: &QueryRoutesRequest,
+    ) -> Result<QueryRoutesResponse> {
+        let request = if query_routes_request.needs_body() {
+            let url = format!("{host}/v1/graph/routes", host = self.host);
+
+            self.client.post(&url).json(query_routes_request)
+        } else {
+            let url = format!(
+                "{host}/v1/graph/routes/{pub_key}/{amt}",
+                host = self.host,
+                pub_key = query_routes_request.pub_key,
+                amt = query_routes_request.amt
+            );
+
+            self.client.get(&url).query(&query_routes_request.query())
+        };
+
+        let mut response = request.send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: QueryRoutesResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to attempt a payment along a given route.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_to_route_request` - A reference to a [SendToRouteRequest] object containing the payment hash and the route to use.
+    ///
+    pub fn send_to_route(&self, send_to_route_request: &SendToRouteRequest) -> Result<HTLCAttempt> {
+        let url = format!("{host}/v2/router/route/send", host = self.host);
+
+        let mut response = self.client.post(&url).json(send_to_route_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: HTLCAttempt = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to build a route through a given list of hops.
+    ///
+    /// # Arguments
+    ///
+    /// * `build_route_request` - A reference to a [BuildRouteRequest] object containing the amount and the public keys of the hops.
+    ///
+    pub fn build_route(
+        &self,
+        build_route_request: &BuildRouteRequest,
+    ) -> Result<BuildRouteResponse> {
+        let url = format!("{host}/v2/router/route", host = self.host);
+
+        let mut response = self.client.post(&url).json(build_route_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: BuildRouteResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the routing fees of each channel and the fees earned over the last day, week and month.
+    pub fn fee_report(&self) -> Result<FeeReportResponse> {
+        let url = format!("{host}/v1/fees", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: FeeReportResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to update the routing policy of all channels or of a single channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy_update_request` - A reference to a [PolicyUpdateRequest] object containing the channels to update and their new policy.
+    ///
+    pub fn update_channel_policy(
+        &self,
+        policy_update_request: &PolicyUpdateRequest,
+    ) -> Result<PolicyUpdateResponse> {
+        let url = format!("{host}/v1/chanpolicy", host = self.host);
+
+        let mut response = self.client.post(&url).json(policy_update_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: PolicyUpdateResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to retrieve a page of the payments forwarded by the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `forwarding_history_request` - A reference to a [ForwardingHistoryRequest] object containing the time window and the page to retrieve.
+    ///
+    pub fn forwarding_history(
+        &self,
+        forwarding_history_request: &ForwardingHistoryRequest,
+    ) -> Result<ForwardingHistoryResponse> {
+        let url = format!("{host}/v1/switch", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(forwarding_history_request)
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ForwardingHistoryResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Retrieve all the payments forwarded by the node, requesting the following pages as the previous ones are consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `forwarding_history_request` - A reference to a [ForwardingHistoryRequest] object containing the time window, the first page to retrieve and the size of the pages.
+    ///
+    pub fn forwarding_events(
+        &self,
+        forwarding_history_request: &ForwardingHistoryRequest,
+    ) -> impl Iterator<Item = Result<ForwardingEvent>> + '_ {
+        let mut request = forwarding_history_request.clone();
+        let mut events = VecDeque::new();
+        let mut done = false;
+
+        std::iter::from_fn(move || loop {
+            if let Some(event) = events.pop_front() {
+                return Some(Ok(event));
+            }
+            if done {
+                return None;
+            }
+
+            match self.forwarding_history(&request) {
+                Ok(page) => {
+                    // The last page is the first one holding no event.
+                    done = page.forwarding_events.is_empty();
+                    request.index_offset = page.last_offset_index;
+                    events.extend(page.forwarding_events);
+                }
+                Err(err) => {
+                    done = true;
+                    return Some(Err(err));
+                }
+            }
+        })
+    }
+
+    /// Send a GET request to generate a new aezeed cipher seed, to be used to create a wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `gen_seed_request` - A reference to a [GenSeedRequest] object containing the optional passphrase and entropy of the seed.
+    ///
+    pub fn gen_seed(&self, gen_seed_request: &GenSeedRequest) -> Result<GenSeedResponse> {
+        let url = format!("{host}/v1/genseed", host = self.host);
+
+        let mut response = self.client.get(&url).query(gen_seed_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: GenSeedResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to create the wallet of a new node from a cipher seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `init_wallet_request` - A reference to a [InitWalletRequest] object containing the password and the cipher seed of the wallet.
+    ///
+    pub fn init_wallet(
+        &self,
+        init_wallet_request: &InitWalletRequest,
+    ) -> Result<InitWalletResponse> {
+        let url = format!("{host}/v1/initwallet", host = self.host);
+
+        let mut response = self.client.post(&url).json(init_wallet_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: InitWalletResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to unlock the wallet of the node after a restart.
+    ///
+    /// # Arguments
+    ///
+    /// * `unlock_wallet_request` - A reference to a [UnlockWalletRequest] object containing the password of the wallet.
+    ///
+    pub fn unlock_wallet(&self, unlock_wallet_request: &UnlockWalletRequest) -> Result<()> {
+        let url = format!("{host}/v1/unlockwallet", host = self.host);
+
+        let response = self.client.post(&url).json(unlock_wallet_request).send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to change the password of the locked wallet, unlocking it.
+    ///
+    /// # Arguments
+    ///
+    /// * `change_password_request` - A reference to a [ChangePasswordRequest] object containing the current and new passwords of the wallet.
+    ///
+    pub fn change_password(
+        &self,
+        change_password_request: &ChangePasswordRequest,
+    ) -> Result<ChangePasswordResponse> {
+        let url = format!("{host}/v1/changepassword", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(change_password_request)
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ChangePasswordResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the current state of the node.
+    pub fn get_state(&self) -> Result<GetStateResponse> {
+        let url = format!("{host}/v1/state", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: GetStateResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to subscribe to the changes of state of the node, starting with its current state.
+    pub fn subscribe_state(&self) -> Result<BlockingStream<SubscribeStateResponse>> {
+        let url = format!("{host}/v1/state/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        Ok(BlockingStream::new(response))
+    }
+
+    /// Wait until the node is started and ready to accept all calls, e.g. after unlocking its wallet.
+    ///
+    /// Return [NodeError::StreamClosed] if the node closes the subscription before becoming active.
+    pub fn wait_for_server_active(&self) -> Result<()> {
+        for state in self.subscribe_state()? {
+            if state?.state == WalletState::SERVER_ACTIVE {
+                return Ok(());
+            }
+        }
+
+        Err(NodeError::StreamClosed)
+    }
+
+    /// Send a GET request to retrieve the payment results recorded by mission control for each pair of nodes.
+    pub fn query_mission_control(&self) -> Result<QueryMissionControlResponse> {
+        let url = format!("{host}/v2/router/mc", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: QueryMissionControlResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to clear all the payment results recorded by mission control.
+    pub fn reset_mission_control(&self) -> Result<()> {
+        let url = format!("{host}/v2/router/mc/reset", host = self.host);
+
+        let response = self.client.post(&url).json(&serde_json::json!({})).send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to import payment results into mission control, e.g. exported from another node.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_import_mission_control_request` - A reference to a [XImportMissionControlRequest] object containing the pair histories to import.
+    ///
+    pub fn x_import_mission_control(
+        &self,
+        x_import_mission_control_request: &XImportMissionControlRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v2/router/x/importhistory", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(x_import_mission_control_request)
+            .send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to estimate the probability of a payment succeeding from a node to another, as seen by mission control.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_node` - The base64 encoded public key of the node sending the payment.
+    /// * `to_node` - The base64 encoded public key of the node receiving the payment.
+    /// * `amt_msat` - The amount of the payment in millisatoshis.
+    ///
+    pub fn query_probability(
+        &self,
+        from_node: &str,
+        to_node: &str,
+        amt_msat: u64,
+    ) -> Result<QueryProbabilityResponse> {
+        let url = format!(
+            "{host}/v2/router/mc/probability/{from_node}/{to_node}/{amt_msat}",
+            host = self.host,
+            from_node = url_safe_base64(from_node),
+            to_node = url_safe_base64(to_node),
+            amt_msat = amt_msat
+        );
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: QueryProbabilityResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the configuration of mission control.
+    pub fn get_mission_control_config(&self) -> Result<GetMissionControlConfigResponse> {
+        let url = format!("{host}/v2/router/mccfg", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: GetMissionControlConfigResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to update the configuration of mission control.
+    ///
+    /// # Arguments
+    ///
+    /// * `set_mission_control_config_request` - A reference to a [SetMissionControlConfigRequest] object containing the new configuration.
+    ///
+    pub fn set_mission_control_config(
+        &self,
+        set_mission_control_config_request: &SetMissionControlConfigRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v2/router/mccfg", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(set_mission_control_config_request)
+            .send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to subscribe to the HTLCs sent, received and forwarded by the node, as they are forwarded, settled or failed.
+    pub fn subscribe_htlc_events(&self) -> Result<BlockingStream<HtlcEvent>> {
+        let url = format!("{host}/v2/router/htlcevents", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        Ok(BlockingStream::new(response))
+    }
+
+    /// Send a POST request to open a channel with a peer, and subscribe to the updates of the opening.
+    ///
+    /// # Arguments
+    ///
+    /// * `open_channel_request` - A reference to an [OpenChannelRequest] object containing the channel parameters.
+    ///
+    pub fn open_channel(
+        &self,
+        open_channel_request: &OpenChannelRequest,
+    ) -> Result<BlockingStream<OpenStatusUpdate>> {
+        let url = format!("{host}/v1/channels/stream", host = self.host);
+
+        let mut response = self.client.post(&url).json(open_channel_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        Ok(BlockingStream::new(response))
+    }
+
+    /// Send a POST request to advance the funding of a channel opened with a funding shim.
+    ///
+    /// # Arguments
+    ///
+    /// * `funding_transition_msg` - A reference to a [FundingTransitionMsg] object containing the funding step.
+    ///
+    pub fn funding_state_step(&self, funding_transition_msg: &FundingTransitionMsg) -> Result<()> {
+        let url = format!("{host}/v1/funding/step", host = self.host);
+
+        let response = self.client.post(&url).json(funding_transition_msg).send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to fund a PSBT with inputs of the wallet, adding a change output if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `fund_psbt_request` - A reference to a [FundPsbtRequest] object containing the PSBT or the outputs to fund.
+    ///
+    pub fn fund_psbt(&self, fund_psbt_request: &FundPsbtRequest) -> Result<FundPsbtResponse> {
+        let url = format!("{host}/v2/wallet/psbt/fund", host = self.host);
+
+        let mut response = self.client.post(&url).json(fund_psbt_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: FundPsbtResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to sign the inputs of a PSBT belonging to the wallet, without finalizing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_psbt_request` - A reference to a [SignPsbtRequest] object containing the PSBT to sign.
+    ///
+    pub fn sign_psbt(&self, sign_psbt_request: &SignPsbtRequest) -> Result<SignPsbtResponse> {
+        let url = format!("{host}/v2/wallet/psbt/sign", host = self.host);
+
+        let mut response = self.client.post(&url).json(sign_psbt_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: SignPsbtResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to sign and finalize a PSBT whose inputs all belong to the wallet, returning the final transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `finalize_psbt_request` - A reference to a [FinalizePsbtRequest] object containing the PSBT to finalize.
+    ///
+    pub fn finalize_psbt(
+        &self,
+        finalize_psbt_request: &FinalizePsbtRequest,
+    ) -> Result<FinalizePsbtResponse> {
+        let url = format!("{host}/v2/wallet/psbt/finalize", host = self.host);
+
+        let mut response = self.client.post(&url).json(finalize_psbt_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: FinalizePsbtResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to publish a signed transaction to the network.
+    ///
+    /// # Arguments
+    ///
+    /// * `publish_transaction_request` - A reference to a [PublishTransactionRequest] object containing the raw transaction and its label.
+    ///
+    pub fn publish_transaction(
+        &self,
+        publish_transaction_request: &PublishTransactionRequest,
+    ) -> Result<PublishResponse> {
+        let url = format!("{host}/v2/wallet/tx", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(publish_transaction_request)
+            .send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: PublishResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to list the wallet outputs currently leased.
+    pub fn list_leases(&self) -> Result<ListLeasesResponse> {
+        let url = format!("{host}/v2/wallet/utxos/leases", host = self.host);
+
+        let mut response = self.client.post(&url).json(&serde_json::json!({})).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ListLeasesResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to lease a wallet output, so it is not selected by other transactions until the lease expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `lease_output_request` - A reference to a [LeaseOutputRequest] object containing the output, the lease id and its duration.
+    ///
+    pub fn lease_output(
+        &self,
+        lease_output_request: &LeaseOutputRequest,
+    ) -> Result<LeaseOutputResponse> {
+        let url = format!("{host}/v2/wallet/utxos/lease", host = self.host);
+
+        let mut response = self.client.post(&url).json(lease_output_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: LeaseOutputResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to release a leased wallet output before its lease expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `release_output_request` - A reference to a [ReleaseOutputRequest] object containing the output and the lease id.
+    ///
+    pub fn release_output(&self, release_output_request: &ReleaseOutputRequest) -> Result<()> {
+        let url = format!("{host}/v2/wallet/utxos/release", host = self.host);
+
+        let response = self.client.post(&url).json(release_output_request).send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to bump the fee of an unconfirmed transaction, by spending one of its outputs belonging to the wallet with a higher fee (CPFP).
+    ///
+    /// # Arguments
+    ///
+    /// * `bump_fee_request` - A reference to a [BumpFeeRequest] object containing the output to spend and the fee to target.
+    ///
+    pub fn bump_fee(&self, bump_fee_request: &BumpFeeRequest) -> Result<()> {
+        let url = format!("{host}/v2/wallet/bumpfee", host = self.host);
+
+        let response = self.client.post(&url).json(bump_fee_request).send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to list the outputs the node is currently trying to sweep.
+    pub fn pending_sweeps(&self) -> Result<PendingSweepsResponse> {
+        let url = format!("{host}/v2/wallet/sweeps/pending", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: PendingSweepsResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to list the transactions published by the node to sweep its outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_sweeps_request` - A reference to a [ListSweepsRequest] object containing the verbosity and the start height.
+    ///
+    pub fn list_sweeps(
+        &self,
+        list_sweeps_request: &ListSweepsRequest,
+    ) -> Result<ListSweepsResponse> {
+        let url = format!("{host}/v2/wallet/sweeps", host = self.host);
+
+        let mut response = self.client.get(&url).query(list_sweeps_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ListSweepsResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to list the accounts of the wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_accounts_request` - A reference to a [ListAccountsRequest] object containing the optional name and address type to filter on.
+    ///
+    pub fn list_accounts(
+        &self,
+        list_accounts_request: &ListAccountsRequest,
+    ) -> Result<ListAccountsResponse> {
+        let url = format!("{host}/v2/wallet/accounts", host = self.host);
+
+        let mut response = self.client.get(&url).query(list_accounts_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ListAccountsResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to import a watch-only account from its extended public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `import_account_request` - A reference to an [ImportAccountRequest] object containing the extended public key and the account name.
+    ///
+    pub fn import_account(
+        &self,
+        import_account_request: &ImportAccountRequest,
+    ) -> Result<ImportAccountResponse> {
+        let url = format!("{host}/v2/wallet/accounts/import", host = self.host);
+
+        let mut response = self.client.post(&url).json(import_account_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ImportAccountResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to import a public key, so the wallet watches the address derived from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `import_public_key_request` - A reference to an [ImportPublicKeyRequest] object containing the public key and its address type.
+    ///
+    pub fn import_public_key(
+        &self,
+        import_public_key_request: &ImportPublicKeyRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v2/wallet/key/import", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(import_public_key_request)
+            .send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to derive the public key at the given key locator.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_locator` - A reference to a [KeyLocator] object containing the key family and index.
+    ///
+    pub fn derive_key(&self, key_locator: &KeyLocator) -> Result<KeyDescriptor> {
+        let url = format!("{host}/v2/wallet/key", host = self.host);
+
+        let mut response = self.client.post(&url).json(key_locator).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: KeyDescriptor = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to derive the next unused public key of a key family.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_req` - A reference to a [KeyReq] object containing the key family.
+    ///
+    pub fn derive_next_key(&self, key_req: &KeyReq) -> Result<KeyDescriptor> {
+        let url = format!("{host}/v2/wallet/key/next", host = self.host);
+
+        let mut response = self.client.post(&url).json(key_req).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: KeyDescriptor = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to list the addresses of the wallet accounts, with their balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_addresses_request` - A reference to a [ListAddressesRequest] object containing the optional account name to filter on.
+    ///
+    pub fn list_addresses(
+        &self,
+        list_addresses_request: &ListAddressesRequest,
+    ) -> Result<ListAddressesResponse> {
+        let url = format!("{host}/v2/wallet/addresses", host = self.host);
+
+        let mut response = self.client.get(&url).query(list_addresses_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ListAddressesResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to sign a message with the identity key of the node, returning a zbase32 encoded signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_message_request` - A reference to a [SignMessageRequest] object containing the message to sign.
+    ///
+    pub fn sign_message(
+        &self,
+        sign_message_request: &SignMessageRequest,
+    ) -> Result<SignMessageResponse> {
+        let url = format!("{host}/v1/signmessage", host = self.host);
+
+        let mut response = self.client.post(&url).json(sign_message_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: SignMessageResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to verify the zbase32 encoded signature of a message, and recover the public key of the signing node.
+    ///
+    /// # Arguments
+    ///
+    /// * `verify_message_request` - A reference to a [VerifyMessageRequest] object containing the message and its signature.
+    ///
+    pub fn verify_message(
+        &self,
+        verify_message_request: &VerifyMessageRequest,
+    ) -> Result<VerifyMessageResponse> {
+        let url = format!("{host}/v1/verifymessage", host = self.host);
+
+        let mut response = self.client.post(&url).json(verify_message_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: VerifyMessageResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to sign a message with a key of the wallet, identified by its key locator.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_message_req` - A reference to a [SignMessageReq] object containing the message and the key locator.
+    ///
+    pub fn sign_message_with_key_locator(
+        &self,
+        sign_message_req: &SignMessageReq,
+    ) -> Result<SignMessageResp> {
+        let url = format!("{host}/v2/signer/signmessage", host = self.host);
+
+        let mut response = self.client.post(&url).json(sign_message_req).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: SignMessageResp = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to verify the signature of a message against a public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `verify_message_req` - A reference to a [VerifyMessageReq] object containing the message, its signature and the public key.
+    ///
+    pub fn verify_message_with_pubkey(
+        &self,
+        verify_message_req: &VerifyMessageReq,
+    ) -> Result<VerifyMessageResp> {
+        let url = format!("{host}/v2/signer/verifymessage", host = self.host);
+
+        let mut response = self.client.post(&url).json(verify_message_req).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: VerifyMessageResp = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to derive the ECDH shared key between an ephemeral public key and a key of the wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `shared_key_request` - A reference to a [SharedKeyRequest] object containing the ephemeral public key.
+    ///
+    pub fn derive_shared_key(
+        &self,
+        shared_key_request: &SharedKeyRequest,
+    ) -> Result<SharedKeyResponse> {
+        let url = format!("{host}/v2/signer/sharedkey", host = self.host);
+
+        let mut response = self.client.post(&url).json(shared_key_request).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: SharedKeyResponse = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to export the static backup of a channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `funding_txid` - The funding transaction id of the channel, as a hex string.
+    /// * `output_index` - The index of the funding output.
+    ///
+    pub fn export_channel_backup(
+        &self,
+        funding_txid: &str,
+        output_index: u32,
+    ) -> Result<ChannelBackup> {
+        let url = format!(
+            "{host}/v1/channels/backup/{funding_txid}/{output_index}",
+            host = self.host,
+            funding_txid = funding_txid,
+            output_index = output_index
+        );
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ChannelBackup = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to export the static backups of all the channels, one per channel and one for all of them.
+    pub fn export_all_channel_backups(&self) -> Result<ChanBackupSnapshot> {
+        let url = format!("{host}/v1/channels/backup", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        let data: ChanBackupSnapshot = response.json()?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to check that channel backups can be decrypted and parsed by the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `chan_backup_snapshot` - A reference to a [ChanBackupSnapshot] object containing the backups to verify.
+    ///
+    pub fn verify_chan_backup(&self, chan_backup_snapshot: &ChanBackupSnapshot) -> Result<()> {
+        let url = format!("{host}/v1/channels/backup/verify", host = self.host);
+
+        let response = self.client.post(&url).json(chan_backup_snapshot).send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to restore channels from their static backups, asking the remote peers to force close them.
+    ///
+    /// # Arguments
+    ///
+    /// * `restore_chan_backup_request` - A reference to a [RestoreChanBackupRequest] object containing the backups to restore.
+    ///
+    pub fn restore_channel_backups(
+        &self,
+        restore_chan_backup_request: &RestoreChanBackupRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v1/channels/backup/restore", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(restore_chan_backup_request)
+            .send()?;
+
+        Self::on_response(response)?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to subscribe to the channel backups, sent again each time a channel is opened or closed.
+    pub fn subscribe_channel_backups(&self) -> Result<BlockingStream<ChanBackupSnapshot>> {
+        let url = format!("{host}/v1/channels/backup/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send()?;
+
+        response = Self::on_response(response)?;
+
+        Ok(BlockingStream::new(response))
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use futures_util::TryStreamExt;
+
+use crate::node::{Node, Result};
+use crate::types::{
+    ClosedChannelsRequest, ForwardingEvent, ForwardingHistoryRequest, ListChannelsRequest,
+};
+
+/// Represent the volume forwarded through a channel or a peer, and the fees earned doing so.
+///
+/// Amounts are in millisatoshis. The fee of a forward is earned on its outgoing channel, where the liquidity was
+/// spent, so it is only added to the outgoing side.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ForwardingStats {
+    /// The number of payments received to be forwarded.
+    pub forwards_in: u64,
+    /// The number of payments forwarded.
+    pub forwards_out: u64,
+    /// The amount received to be forwarded.
+    pub volume_in_msat: u64,
+    /// The amount forwarded.
+    pub volume_out_msat: u64,
+    /// The fees earned.
+    pub fee_msat: u64,
+}
+
+impl ForwardingStats {
+    fn add_in(&mut self, amt_msat: u64) {
+        self.forwards_in += 1;
+        self.volume_in_msat += amt_msat;
+    }
+
+    fn add_out(&mut self, amt_msat: u64, fee_msat: u64) {
+        self.forwards_out += 1;
+        self.volume_out_msat += amt_msat;
+        self.fee_msat += fee_msat;
+    }
+}
+
+/// Aggregate forwarding events per channel and per peer, to report the volume routed and the fees earned over a time
+/// window.
+///
+/// Peers are identified by their public key, resolved from the short channel ids of the events with a map built by
+/// [channel_peers]. Events through a channel missing from the map are only counted per channel.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ForwardingReport {
+    /// The stats of all the events.
+    pub total: ForwardingStats,
+    /// The stats of each channel, indexed by short channel id.
+    pub channels: HashMap<String, ForwardingStats>,
+    /// The stats of each peer, indexed by public key.
+    pub peers: HashMap<String, ForwardingStats>,
+    /// The alias of each peer, indexed by public key, for display only as aliases are neither unique nor stable. Only
+    /// filled if the events were retrieved with `peer_alias_lookup` set.
+    pub aliases: HashMap<String, String>,
+}
+
+impl ForwardingReport {
+    /// Create a [ForwardingReport] from a list of forwarding events.
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - The forwarding events to aggregate.
+    /// * `channel_peers` - A reference to the public keys of the remote peers, indexed by short channel id.
+    ///
+    pub fn new<'a, I>(events: I, channel_peers: &HashMap<String, String>) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a ForwardingEvent>,
+    {
+        let mut report = ForwardingReport::default();
+
+        for event in events {
+            report.add(event, channel_peers)?;
+        }
+
+        Ok(report)
+    }
+
+    /// Create a [ForwardingReport] from all the payments forwarded by the node within a time window.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node to retrieve the forwarding events and the channels from.
+    /// * `start_time` - The start of the time window, as a Unix timestamp in seconds.
+    /// * `end_time` - The end of the time window, as a Unix timestamp in seconds.
+    ///
+    pub async fn load(node: &Node, start_time: u64, end_time: u64) -> Result<Self> {
+        let forwarding_history_request = ForwardingHistoryRequest {
+            start_time,
+            end_time,
+            num_max_events: 1000,
+            peer_alias_lookup: true,
+            ..Default::default()
+        };
+
+        let events: Vec<ForwardingEvent> = node
+            .forwarding_events(&forwarding_history_request)
+            .try_collect()
+            .await?;
+
+        let channel_peers = channel_peers(node).await?;
+
+        Self::new(&events, &channel_peers)
+    }
+
+    /// Add a forwarding event to the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - A reference to the forwarding event to add.
+    /// * `channel_peers` - A reference to the public keys of the remote peers, indexed by short channel id.
+    ///
+    pub fn add(
+        &mut self,
+        event: &ForwardingEvent,
+        channel_peers: &HashMap<String, String>,
+    ) -> Result<()> {
+        let amt_in_msat: u64 = event.amt_in_msat.parse()?;
+        let amt_out_msat: u64 = event.amt_out_msat.parse()?;
+        let fee_msat: u64 = event.fee_msat.parse()?;
+
+        self.total.add_in(amt_in_msat);
+        self.channels
+            .entry(event.chan_id_in.clone())
+            .or_default()
+            .add_in(amt_in_msat);
+        if let Some(pubkey) = channel_peers.get(&event.chan_id_in) {
+            self.peers
+                .entry(pubkey.clone())
+                .or_default()
+                .add_in(amt_in_msat);
+            self.add_alias(pubkey, &event.peer_alias_in);
+        }
+
+        self.total.add_out(amt_out_msat, fee_msat);
+        self.channels
+            .entry(event.chan_id_out.clone())
+            .or_default()
+            .add_out(amt_out_msat, fee_msat);
+        if let Some(pubkey) = channel_peers.get(&event.chan_id_out) {
+            self.peers
+                .entry(pubkey.clone())
+                .or_default()
+                .add_out(amt_out_msat, fee_msat);
+            self.add_alias(pubkey, &event.peer_alias_out);
+        }
+
+        Ok(())
+    }
+
+    fn add_alias(&mut self, pubkey: &str, alias: &str) {
+        if !alias.is_empty() {
+            self.aliases.insert(pubkey.to_string(), alias.to_string());
+        }
+    }
+}
+
+/// Return the public keys of the remote peers of the open and closed channels of the node, indexed by short channel id.
+///
+/// # Arguments
+///
+/// * `node` - The node to retrieve the channels from.
+///
+pub async fn channel_peers(node: &Node) -> Result<HashMap<String, String>> {
+    let open = node
+        .list_channels(&ListChannelsRequest::default())
+        .await?
+        .channels
+        .into_iter()
+        .map(|channel| (channel.chan_id, channel.remote_pubkey));
+
+    let closed = node
+        .closed_channels(&ClosedChannelsRequest::default())
+        .await?
+        .channels
+        .into_iter()
+        .map(|summary| (summary.chan_id, summary.remote_pubkey));
+
+    Ok(closed.chain(open).collect())
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::node::{Node, Result};
+use crate::types::{
+    ChannelEdge, ChannelEdgeUpdate, ChannelGraph, GraphTopologyUpdate, LightningNode, NodeUpdate,
+    RoutingPolicy,
+};
+
+/// Keep an in-memory copy of the channel graph, to query nodes, channels and routing policies without calling the node.
+///
+/// The cache starts from a snapshot returned by [Node::describe_graph] and is kept up to date by applying the updates
+/// streamed by [Node::subscribe_channel_graph]. To avoid missing updates sent while the snapshot is being fetched,
+/// subscribe before loading the cache:
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use lnd_rest::graph::GraphCache;
+/// use lnd_rest::node::Node;
+///
+/// # async fn run(node: Node) -> lnd_rest::node::Result<()> {
+/// let mut updates = node.subscribe_channel_graph().await?;
+/// let mut cache = GraphCache::load(&node, false).await?;
+///
+/// while let Some(update) = updates.next().await {
+///     cache.apply(&update?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GraphCache {
+    nodes: HashMap<String, LightningNode>,
+    edges: HashMap<String, ChannelEdge>,
+}
+
+impl GraphCache {
+    /// Create a [GraphCache] from a snapshot of the channel graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The snapshot of the channel graph.
+    ///
+    pub fn new(graph: ChannelGraph) -> Self {
+        GraphCache {
+            nodes: graph
+                .nodes
+                .into_iter()
+                .map(|node| (node.pub_key.clone(), node))
+                .collect(),
+            edges: graph
+                .edges
+                .into_iter()
+                .map(|edge| (edge.channel_id.clone(), edge))
+                .collect(),
+        }
+    }
+
+    /// Create a [GraphCache] from the channel graph currently known to the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node to fetch the channel graph from.
+    /// * `include_unannounced` - Whether to include the private channels and the channels not yet announced.
+    ///
+    pub async fn load(node: &Node, include_unannounced: bool) -> Result<Self> {
+        let graph = node.describe_graph(include_unannounced).await?;
+
+        Ok(Self::new(graph))
+    }
+
+    /// Apply the node, channel and closed channel updates of a [GraphTopologyUpdate].
+    ///
+    /// Node updates carry no timestamp, as the node relays each announcement as soon as it is received, so the
+    /// `last_update` of an updated node is set to the time the update is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - A reference to the update sent by the node.
+    ///
+    pub fn apply(&mut self, update: &GraphTopologyUpdate) {
+        for node_update in &update.node_updates {
+            self.apply_node_update(node_update);
+        }
+
+        for channel_update in &update.channel_updates {
+            self.apply_channel_update(channel_update);
+        }
+
+        for closed in &update.closed_chans {
+            self.edges.remove(&closed.chan_id);
+        }
+    }
+
+    fn apply_node_update(&mut self, update: &NodeUpdate) {
+        let node = self
+            .nodes
+            .entry(update.identity_key.clone())
+            .or_insert_with(|| LightningNode {
+                last_update: 0,
+                pub_key: update.identity_key.clone(),
+                alias: String::new(),
+                addresses: Vec::new(),
+                color: String::new(),
+                features: HashMap::new(),
+            });
+
+        node.last_update = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as u32;
+        node.alias = update.alias.clone();
+        node.color = update.color.clone();
+        node.addresses = update.node_addresses.clone();
+        node.features = update.features.clone();
+    }
+
+    fn apply_channel_update(&mut self, update: &ChannelEdgeUpdate) {
+        let edge = self.edges.entry(update.chan_id.clone()).or_insert_with(|| {
+            // The first node of a channel is the one with the lexicographically lower public key.
+            let (node1_pub, node2_pub) = if update.advertising_node < update.connecting_node {
+                (&update.advertising_node, &update.connecting_node)
+            } else {
+                (&update.connecting_node, &update.advertising_node)
+            };
+            let chan_point = update
+                .chan_point
+                .txid()
+                .map(|txid| format!("{}:{}", txid, update.chan_point.output_index))
+                .unwrap_or_default();
+
+            ChannelEdge {
+                channel_id: update.chan_id.clone(),
+                chan_point,
+                node1_pub: node1_pub.clone(),
+                node2_pub: node2_pub.clone(),
+                capacity: update.capacity.clone(),
+                node1_policy: None,
+                node2_policy: None,
+            }
+        });
+
+        edge.capacity = update.capacity.clone();
+
+        if let Some(policy) = &update.routing_policy {
+            if update.advertising_node == edge.node1_pub {
+                edge.node1_policy = Some(policy.clone());
+            } else {
+                edge.node2_policy = Some(policy.clone());
+            }
+        }
+    }
+
+    /// Return the node with the given public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - The hex encoded public key of the node.
+    ///
+    pub fn node(&self, pub_key: &str) -> Option<&LightningNode> {
+        self.nodes.get(pub_key)
+    }
+
+    /// Return the channel with the given short channel id.
+    ///
+    /// # Arguments
+    ///
+    /// * `chan_id` - The short channel id of the channel.
+    ///
+    pub fn edge(&self, chan_id: &str) -> Option<&ChannelEdge> {
+        self.edges.get(chan_id)
+    }
+
+    /// Return the routing policy set by a node for forwarding payments through one of its channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `chan_id` - The short channel id of the channel.
+    /// * `pub_key` - The hex encoded public key of the node forwarding through the channel.
+    ///
+    pub fn policy(&self, chan_id: &str, pub_key: &str) -> Option<&RoutingPolicy> {
+        let edge = self.edges.get(chan_id)?;
+
+        if edge.node1_pub == pub_key {
+            edge.node1_policy.as_ref()
+        } else if edge.node2_pub == pub_key {
+            edge.node2_policy.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Return the channels of a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - The hex encoded public key of the node.
+    ///
+    pub fn node_edges<'a>(&'a self, pub_key: &'a str) -> impl Iterator<Item = &'a ChannelEdge> {
+        self.edges
+            .values()
+            .filter(move |edge| edge.node1_pub == pub_key || edge.node2_pub == pub_key)
+    }
+
+    /// Return all the nodes of the graph.
+    pub fn nodes(&self) -> impl Iterator<Item = &LightningNode> {
+        self.nodes.values()
+    }
+
+    /// Return all the channels of the graph.
+    pub fn edges(&self) -> impl Iterator<Item = &ChannelEdge> {
+        self.edges.values()
+    }
+}
@@ -2,6 +2,16 @@
 //!
 //! Rust wrapper to interact with the REST API of a Lightning Network Daemon node.
 //!
+//! ## Features
+//!
+//! - `native-tls` (default): use the platform TLS library (OpenSSL on Linux).
+//! - `rustls`: use [rustls](https://github.com/rustls/rustls) instead, and allow pinning the node certificate. Certificate
+//!   pinning is only available with this feature, as `NodeOptions::cert_fingerprint` does not exist without it.
+//! - `blocking`: provide [BlockingNode](crate::blocking::BlockingNode), a synchronous mirror of [Node](crate::node::Node).
+//! - `websocket`: provide the bidirectional streams served over WebSocket, such as the HTLC interceptor and the channel acceptor.
+//!
+//! One of `native-tls` or `rustls` must be enabled: disabling the default features without enabling `rustls` fails to compile.
+//!
 //! ## Usage
 //!
 //! Add package to your `Cargo.toml` manifest:
@@ -12,7 +22,7 @@
 //!
 //! ### Add a new invoice
 //!
-//! ```rust
+//! ```rust,no_run
 //! use lnd_rest::node::Node;
 //! use lnd_rest::types::AddInvoiceRequest;
 //!
@@ -47,7 +57,7 @@
 //!
 //! ### Lookup an invoice
 //!
-//! ```rust
+//! ```rust,no_run
 //! use lnd_rest::node::Node;
 //!
 //! #[tokio::main]
@@ -76,7 +86,7 @@
 //!
 //! ### Pay an invoice
 //!
-//! ```rust
+//! ```rust,no_run
 //! use lnd_rest::node::Node;
 //! use lnd_rest::types::SendPaymentSyncRequest;
 //!
@@ -110,7 +120,7 @@
 //!
 //! ### List payments
 //!
-//! ```rust
+//! ```rust,no_run
 //! use lnd_rest::node::Node;
 //! use lnd_rest::types::ListPaymentsRequest;
 //!
@@ -143,6 +153,137 @@
 //!     }
 //! }
 //! ```
+//!
+//! ### Connect through a SOCKS5 proxy
+//!
+//! ```rust,no_run
+//! use lnd_rest::node::{Node, NodeOptions};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let host = "https://your_lnd_host.onion:8080".to_string();
+//!     let macaroon_path = "path/to/macaroon".to_string();
+//!     let cert_path = "path/to/cert".to_string();
+//!
+//!     let options = NodeOptions {
+//!         proxy: Some("127.0.0.1:9050".to_string()), // Set the address of your Tor SOCKS5 proxy
+//!         ..Default::default()
+//!     };
+//!
+//!     let node = Node::init_with_options(host, macaroon_path, cert_path, &options)
+//!         .await
+//!         .unwrap();
+//!
+//!     // Every request made by the node is now routed through the proxy
+//! }
+//! ```
+//!
+//! ### Unlock the wallet after a restart
+//!
+//! The wallet unlocker and the state service do not require a macaroon.
+//!
+//! ```rust,no_run
+//! use lnd_rest::node::{Node, NodeOptions};
+//! use lnd_rest::types::{UnlockWalletRequest, WalletState};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let host = "your_lnd_host".to_string();
+//!     let cert_path = "path/to/cert".to_string();
+//!
+//!     let unlocker = Node::init_without_macaroon(host, cert_path, &NodeOptions::default())
+//!         .await
+//!         .unwrap();
+//!
+//!     if unlocker.get_state().await.unwrap().state == WalletState::LOCKED {
+//!         let unlock_wallet_request = UnlockWalletRequest {
+//!             wallet_password: "your_base64_encoded_password".to_string(),
+//!             ..Default::default()
+//!         };
+//!         unlocker.unlock_wallet(&unlock_wallet_request).await.unwrap();
+//!     }
+//!
+//!     // Wait for the node to accept all calls before using a node initialized with a macaroon
+//!     unlocker.wait_for_server_active().await.unwrap();
+//! }
+//! ```
+//!
+//! ### Pin the node certificate
+//!
+//! LND generates a self-signed certificate whose names often do not match the address used to reach the node.
+//! With the `rustls` feature, the certificate can instead be pinned by its SHA-256 fingerprint, as printed by
+//! `openssl x509 -in tls.cert -noout -fingerprint -sha256`:
+//!
+//! ```toml
+//! [dependencies]
+//! lnd_rest = { version = "0.3", default-features = false, features = ["rustls"] }
+//! ```
+//!
+#![cfg_attr(feature = "rustls", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "rustls"), doc = "```rust,ignore")]
+//! use lnd_rest::node::{Node, NodeOptions};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let host = "https://your_lnd_host:8080".to_string();
+//!     let macaroon_path = "path/to/macaroon".to_string();
+//!     let cert_path = "path/to/cert".to_string(); // Not read when the certificate is pinned
+//!
+//!     let options = NodeOptions {
+//!         cert_fingerprint: Some("your_cert_sha256_fingerprint".to_string()),
+//!         ..Default::default()
+//!     };
+//!
+//!     let node = Node::init_with_options(host, macaroon_path, cert_path, &options)
+//!         .await
+//!         .unwrap();
+//!
+//!     // Requests fail with `NodeError::CertFingerprintMismatch` if the node presents another certificate
+//! }
+//! ```
+//!
+//! ### Use the blocking client
+//!
+//! ```toml
+//! [dependencies]
+//! lnd_rest = { version = "0.3", features = ["blocking"] }
+//! ```
+//!
+#![cfg_attr(feature = "blocking", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "blocking"), doc = "```rust,ignore")]
+//! use lnd_rest::blocking::BlockingNode;
+//!
+//! fn main() {
+//!     let host = "your_lnd_host".to_string();
+//!     let macaroon_path = "path/to/macaroon".to_string();
+//!     let cert_path = "path/to/cert".to_string();
+//!
+//!     let node = BlockingNode::init(host, macaroon_path, cert_path).unwrap();
+//!
+//!     let payment_hash = "your_payment_hash".to_string();
+//!
+//!     match node.lookup_invoice(&payment_hash) {
+//!         Ok(response) => println!("Invoice state: {:?}", response.state),
+//!         Err(err) => eprintln!("Error looking up invoice: {}", err),
+//!     }
+//! }
+//! ```
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("enable either the native-tls or rustls feature");
 
+pub mod backup;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod forwarding;
+pub mod graph;
 pub mod node;
+pub mod pool;
+pub mod psbt;
+pub mod stream;
+#[cfg(feature = "rustls")]
+mod tls;
 pub mod types;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+pub mod withdrawals;
@@ -1,15 +1,49 @@
+use std::collections::VecDeque;
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
 use std::process::Command;
 
+#[cfg(feature = "websocket")]
+use futures_util::SinkExt;
+use futures_util::{stream, StreamExt};
 use reqwest::Client;
 use thiserror::Error;
 
+use crate::stream::{json_lines, NodeStream, RpcError};
 use crate::types::{
-    AddInvoiceRequest, AddInvoiceResponse, ListPaymentsRequest, ListPaymentsResponse,
-    LookupInvoiceResponse, SendPaymentSyncRequest, SendPaymentSyncResponse,
+    AddInvoiceRequest, AddInvoiceResponse, BuildRouteRequest, BuildRouteResponse, BumpFeeRequest,
+    ChanBackupSnapshot, ChangePasswordRequest, ChangePasswordResponse, ChannelBackup, ChannelEdge,
+    ChannelEventUpdate, ChannelGraph, ClosedChannelsRequest, ClosedChannelsResponse,
+    ConnectPeerRequest, EstimateFeeRequest, EstimateFeeResponse, FeeReportResponse, FeeTier,
+    FinalizePsbtRequest, FinalizePsbtResponse, ForwardingEvent, ForwardingHistoryRequest,
+    ForwardingHistoryResponse, FundPsbtRequest, FundPsbtResponse, FundingTransitionMsg,
+    GenSeedRequest, GenSeedResponse, GetInfoResponse, GetMissionControlConfigResponse,
+    GetStateResponse, GetTransactionsRequest, GraphTopologyUpdate, HTLCAttempt, HtlcEvent,
+    ImportAccountRequest, ImportAccountResponse, ImportPublicKeyRequest, InitWalletRequest,
+    InitWalletResponse, KeyDescriptor, KeyLocator, KeyReq, LeaseOutputRequest, LeaseOutputResponse,
+    ListAccountsRequest, ListAccountsResponse, ListAddressesRequest, ListAddressesResponse,
+    ListChannelsRequest, ListChannelsResponse, ListLeasesResponse, ListPaymentsRequest,
+    ListPaymentsResponse, ListPeersResponse, ListSweepsRequest, ListSweepsResponse,
+    ListUnspentRequest, ListUnspentResponse, LookupInvoiceResponse, NetworkInfo, NewAddressRequest,
+    NewAddressResponse, NodeInfo, NodeMetricsResponse, OpenChannelRequest, OpenStatusUpdate,
+    PeerEvent, PendingSweepsResponse, PolicyUpdateRequest, PolicyUpdateResponse, PublishResponse,
+    PublishTransactionRequest, QueryMissionControlResponse, QueryProbabilityResponse,
+    QueryRoutesRequest, QueryRoutesResponse, ReleaseOutputRequest, RestoreChanBackupRequest,
+    SendCoinsRequest, SendCoinsResponse, SendManyRequest, SendManyResponse, SendPaymentSyncRequest,
+    SendPaymentSyncResponse, SendToRouteRequest, SetMissionControlConfigRequest, SharedKeyRequest,
+    SharedKeyResponse, SignMessageReq, SignMessageRequest, SignMessageResp, SignMessageResponse,
+    SignPsbtRequest, SignPsbtResponse, SubscribeStateResponse, Transaction, TransactionDetails,
+    UnlockWalletRequest, VerifyMessageReq, VerifyMessageRequest, VerifyMessageResp,
+    VerifyMessageResponse, WalletEstimateFeeResponse, WalletState, XImportMissionControlRequest,
 };
+#[cfg(feature = "websocket")]
+use crate::types::{
+    ChannelAcceptRequest, ChannelAcceptResponse, ForwardHtlcInterceptRequest,
+    ForwardHtlcInterceptResponse,
+};
+#[cfg(feature = "websocket")]
+use crate::websocket::NodeSink;
 
 /// Make it easier to handle and propagate errors using the NodeError enum as the error type.
 pub type Result<T> = std::result::Result<T, NodeError>;
@@ -22,10 +56,106 @@ pub enum NodeError {
     IoError(#[from] std::io::Error),
     /// An error occurred while making a request.
     #[error("Request error: {0}")]
-    RequestError(#[from] reqwest::Error),
+    RequestError(reqwest::Error),
     /// The request header contained an invalid value.
     #[error("Invalid request header value: {0}")]
     RequestHeaderError(#[from] reqwest::header::InvalidHeaderValue),
+    /// The pinned certificate fingerprint is not a valid SHA-256 fingerprint.
+    #[error("Invalid certificate fingerprint: {0}")]
+    InvalidCertFingerprint(String),
+    /// The certificate presented by the node does not match the pinned certificate fingerprint.
+    #[error("Certificate fingerprint mismatch: expected {expected}, found {found}")]
+    CertFingerprintMismatch {
+        /// The pinned fingerprint.
+        expected: String,
+        /// The fingerprint of the certificate presented by the node.
+        found: String,
+    },
+    /// None of the nodes of a pool is healthy.
+    #[error("No healthy node available")]
+    NoHealthyNode,
+    /// An error occurred while decoding a message sent by the node.
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+    /// The node returned an error.
+    #[error("LND error {code}: {message}")]
+    ApiError {
+        /// The gRPC status code of the error.
+        code: i32,
+        /// The error message.
+        message: String,
+    },
+    /// The node closed a stream before sending the expected message.
+    #[error("Stream closed by the node")]
+    StreamClosed,
+    /// The node sent a stream message holding neither a result nor an error.
+    #[error("Empty stream message")]
+    EmptyFrame,
+    /// The node sent an update out of order while opening a channel funded by a PSBT.
+    #[error("Unexpected channel opening update: {0:?}")]
+    UnexpectedUpdate(Box<crate::types::OpenStatusUpdateKind>),
+    /// The transaction has no output belonging to the wallet, so its fee cannot be bumped with CPFP.
+    #[error("Transaction {0} has no output belonging to the wallet")]
+    NoWalletOutput(String),
+    /// A number sent by the node could not be parsed.
+    #[error("Invalid number: {0}")]
+    ParseIntError(#[from] std::num::ParseIntError),
+    /// Bytes sent by the node could not be decoded from base64.
+    #[error("Invalid base64: {0}")]
+    Base64Error(#[from] base64::DecodeError),
+    /// The address is not a valid `pubkey@host` Lightning Network address.
+    #[error("Invalid Lightning Network address: {0}")]
+    InvalidLightningAddress(String),
+    /// The host address of the node is not a valid `http` or `https` URL.
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+    /// The SOCKS5 proxy failed to open a connection.
+    #[cfg(feature = "websocket")]
+    #[error("Proxy error: {0}")]
+    ProxyError(#[from] tokio_socks::Error),
+    /// An error occurred on a WebSocket connection.
+    #[cfg(feature = "websocket")]
+    #[error("WebSocket error: {0}")]
+    WebSocketError(Box<tokio_tungstenite::tungstenite::Error>),
+}
+
+impl NodeError {
+    /// Return true if the error was caused by a failure to connect to the node, in which case the request was not sent.
+    pub fn is_connect(&self) -> bool {
+        match self {
+            NodeError::RequestError(err) => err.is_connect(),
+            _ => false,
+        }
+    }
+}
+
+impl From<reqwest::Error> for NodeError {
+    fn from(err: reqwest::Error) -> Self {
+        #[cfg(feature = "rustls")]
+        if let Some(mismatch) = crate::tls::find_fingerprint_mismatch(&err) {
+            return NodeError::CertFingerprintMismatch {
+                expected: mismatch.expected.to_string(),
+                found: mismatch.found.to_string(),
+            };
+        }
+
+        NodeError::RequestError(err)
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl From<tokio_tungstenite::tungstenite::Error> for NodeError {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        #[cfg(feature = "rustls")]
+        if let Some(mismatch) = crate::tls::find_fingerprint_mismatch(&err) {
+            return NodeError::CertFingerprintMismatch {
+                expected: mismatch.expected.to_string(),
+                found: mismatch.found.to_string(),
+            };
+        }
+
+        NodeError::WebSocketError(Box::new(err))
+    }
 }
 
 /// Encapsulate data needed to interact with a Lightning Network Daemon (LND) node.
@@ -35,6 +165,110 @@ pub struct Node {
     pub host: String,
     /// The HTTP client used to communicate with the LND node.
     pub client: Client,
+    /// The settings used to open WebSocket connections with the LND node.
+    #[cfg(feature = "websocket")]
+    pub(crate) websocket: crate::websocket::WebSocketConfig,
+}
+
+/// Optional settings used when initializing a [Node].
+#[derive(Clone, Debug, Default)]
+pub struct NodeOptions {
+    /// The address of a SOCKS5 proxy to route every request through, e.g. `127.0.0.1:9050` for a local Tor daemon.
+    ///
+    /// Host names are resolved by the proxy, so `.onion` addresses can be used as the node host.
+    pub proxy: Option<String>,
+    /// The SHA-256 fingerprint of the node certificate, as 64 hexadecimal digits optionally separated by colons.
+    ///
+    /// When set, the connection is only trusted if the node presents this exact certificate, regardless of the
+    /// names it contains, and the certificate file is not read.
+    #[cfg(feature = "rustls")]
+    pub cert_fingerprint: Option<String>,
+}
+
+impl NodeOptions {
+    /// Build the TLS configuration pinning the node certificate, if a fingerprint is set.
+    #[cfg(feature = "rustls")]
+    pub(crate) fn pinned_tls_config(&self) -> Result<Option<rustls::ClientConfig>> {
+        match &self.cert_fingerprint {
+            Some(fingerprint) => {
+                let fingerprint = crate::tls::CertFingerprint::parse(fingerprint)?;
+                Ok(Some(crate::tls::pinned_config(fingerprint)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Build the TLS configuration pinning the node certificate, if a fingerprint is set.
+    #[cfg(not(feature = "rustls"))]
+    pub(crate) fn pinned_tls_config(&self) -> Result<Option<std::convert::Infallible>> {
+        Ok(None)
+    }
+}
+
+/// Read the macaroon file and build the headers to send with every request.
+///
+/// # Arguments
+///
+/// * `macaroon_path` - The path to the macaroon file.
+///
+pub(crate) fn macaroon_headers<P: AsRef<Path>>(
+    macaroon_path: P,
+) -> Result<reqwest::header::HeaderMap> {
+    let cmd_output = Command::new("xxd")
+        .args(["-ps", "-u", "-c", "1000"])
+        .arg(macaroon_path.as_ref())
+        .output()?;
+
+    let mut macaroon = cmd_output.stdout;
+    macaroon.retain(|&z| {
+        ((z >= b'0' as _) && (z <= b'9' as _)) | ((z >= b'A' as _) && (z <= b'F' as _))
+    });
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    let mut macaroon_value = reqwest::header::HeaderValue::from_bytes(&macaroon)?;
+    macaroon_value.set_sensitive(true);
+    headers.insert("Grpc-Metadata-macaroon", macaroon_value);
+
+    Ok(headers)
+}
+
+/// Read the certificate file to trust as root certificate.
+///
+/// # Arguments
+///
+/// * `cert_path` - The path to the certificate file.
+///
+pub(crate) fn read_certificate<P: AsRef<Path>>(cert_path: P) -> Result<reqwest::Certificate> {
+    let mut cert_file = File::open(cert_path)?;
+    let mut cert_raw = Vec::new();
+    cert_file.read_to_end(&mut cert_raw)?;
+    let cert = reqwest::Certificate::from_pem(&cert_raw)?;
+
+    Ok(cert)
+}
+
+/// Build the proxy routing every request through the given SOCKS5 proxy.
+///
+/// # Arguments
+///
+/// * `proxy` - The address of the SOCKS5 proxy.
+///
+pub(crate) fn socks_proxy(proxy: &str) -> Result<reqwest::Proxy> {
+    // The `socks5h` scheme lets the proxy resolve host names, which is required for `.onion` hosts.
+    let proxy_url = format!("socks5h://{proxy}", proxy = proxy);
+    let proxy = reqwest::Proxy::all(proxy_url)?;
+
+    Ok(proxy)
+}
+
+/// Convert base64 encoded bytes to the URL-safe alphabet, so that they can be used as a path parameter.
+///
+/// # Arguments
+///
+/// * `bytes` - The base64 encoded bytes, in the standard or URL-safe alphabet.
+///
+pub(crate) fn url_safe_base64(bytes: &str) -> String {
+    bytes.replace('+', "-").replace('/', "_")
 }
 
 impl Node {
@@ -51,36 +285,94 @@ impl Node {
         macaroon_path: P,
         cert_path: P,
     ) -> Result<Self> {
-        let mut cert_file = File::open(cert_path)?;
-        let mut cert_raw = Vec::new();
-        cert_file.read_to_end(&mut cert_raw)?;
-        let cert = reqwest::Certificate::from_pem(&cert_raw)?;
+        Self::init_with_options(host, macaroon_path, cert_path, &NodeOptions::default()).await
+    }
+
+    /// Initialize a [Node] object using the macaroon and certificate files provided, and the given options.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host address to connect to.
+    /// * `macaroon_path` - The path to the macaroon file.
+    /// * `cert_path` - The path to the certificate file.
+    /// * `options` - A reference to a [NodeOptions] object containing the optional settings.
+    ///
+    pub async fn init_with_options<P: AsRef<Path>>(
+        host: String,
+        macaroon_path: P,
+        cert_path: P,
+        options: &NodeOptions,
+    ) -> Result<Self> {
+        let headers = macaroon_headers(macaroon_path)?;
+
+        Self::init_with_headers(host, headers, cert_path, options)
+    }
+
+    /// Initialize a [Node] object sending no macaroon, using the certificate file provided and the given options.
+    ///
+    /// Such a node can only call the methods that do not require a macaroon: [Node::gen_seed], [Node::init_wallet],
+    /// [Node::unlock_wallet], [Node::change_password], [Node::get_state] and [Node::subscribe_state]. This lets a
+    /// wallet be created or unlocked before a macaroon exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host address to connect to.
+    /// * `cert_path` - The path to the certificate file.
+    /// * `options` - A reference to a [NodeOptions] object containing the optional settings.
+    ///
+    pub async fn init_without_macaroon<P: AsRef<Path>>(
+        host: String,
+        cert_path: P,
+        options: &NodeOptions,
+    ) -> Result<Self> {
+        Self::init_with_headers(host, reqwest::header::HeaderMap::new(), cert_path, options)
+    }
+
+    /// Build the HTTP client sending the given headers with every request.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host address to connect to.
+    /// * `headers` - The headers to send with every request.
+    /// * `cert_path` - The path to the certificate file.
+    /// * `options` - A reference to a [NodeOptions] object containing the optional settings.
+    ///
+    fn init_with_headers<P: AsRef<Path>>(
+        host: String,
+        headers: reqwest::header::HeaderMap,
+        cert_path: P,
+        options: &NodeOptions,
+    ) -> Result<Self> {
+        #[cfg(feature = "websocket")]
+        let websocket =
+            crate::websocket::WebSocketConfig::new(headers.clone(), &cert_path, options)?;
 
-        let cmd_output = Command::new("xxd")
-            .args(["-ps", "-u", "-c", "1000"])
-            .arg(macaroon_path.as_ref())
-            .output()?;
+        let mut builder = reqwest::Client::builder().default_headers(headers);
 
-        let mut macaroon = cmd_output.stdout;
-        macaroon.retain(|&z| {
-            ((z >= b'0' as _) && (z <= b'9' as _)) | ((z >= b'A' as _) && (z <= b'F' as _))
-        });
+        match options.pinned_tls_config()? {
+            #[cfg(feature = "rustls")]
+            Some(tls) => builder = builder.use_preconfigured_tls(tls),
+            _ => builder = builder.add_root_certificate(read_certificate(cert_path)?),
+        }
 
-        let mut headers = reqwest::header::HeaderMap::new();
-        let mut macaroon_value = reqwest::header::HeaderValue::from_bytes(&macaroon)?;
-        macaroon_value.set_sensitive(true);
-        headers.insert("Grpc-Metadata-macaroon", macaroon_value);
+        if let Some(proxy) = &options.proxy {
+            builder = builder.proxy(socks_proxy(proxy)?);
+        }
 
-        let client = reqwest::Client::builder()
-            .add_root_certificate(cert)
-            .default_headers(headers)
-            .build()?;
+        let client = builder.build()?;
 
-        Ok(Node { host, client })
+        Ok(Node {
+            host,
+            client,
+            #[cfg(feature = "websocket")]
+            websocket,
+        })
     }
 
     /// Handle the response from an HTTP request and perform error checking based on the response status code.
     ///
+    /// Errors described by the node in the response body are returned as [NodeError::ApiError].
+    ///
     /// # Arguments
     ///
     /// * `response` - An object representing the HTTP response.
@@ -90,9 +382,17 @@ impl Node {
 
         match status {
             reqwest::StatusCode::OK => Ok(response),
-            _ => match response.error_for_status() {
-                Ok(res) => Ok(res),
-                Err(err) => Err(err.into()),
+            _ => match response.error_for_status_ref() {
+                Ok(_) => Ok(response),
+                Err(err) => {
+                    // LND describes the error in the body, which is more helpful than the status code alone.
+                    let body = response.bytes().await?;
+
+                    match serde_json::from_slice::<RpcError>(&body) {
+                        Ok(error) => Err(error.into()),
+                        Err(_) => Err(err.into()),
+                    }
+                }
             },
         }
     }
@@ -188,4 +488,1658 @@ impl Node {
 
         Ok(data)
     }
+
+    /// Send a GET request to retrieve general information about the node.
+    pub async fn get_info(&self) -> Result<GetInfoResponse> {
+        let url = format!("{host}/v1/getinfo", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: GetInfoResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the list of connected peers.
+    ///
+    /// # Arguments
+    ///
+    /// * `latest_error` - Whether to only return the latest error of each peer, instead of all recorded errors.
+    ///
+    pub async fn list_peers(&self, latest_error: bool) -> Result<ListPeersResponse> {
+        let url = format!(
+            "{host}/v1/peers?latest_error={latest_error}",
+            host = self.host,
+            latest_error = latest_error
+        );
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ListPeersResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to connect to a peer.
+    ///
+    /// # Arguments
+    ///
+    /// * `connect_peer_request` - A reference to a [ConnectPeerRequest] object containing the address of the peer.
+    ///
+    pub async fn connect_peer(&self, connect_peer_request: &ConnectPeerRequest) -> Result<()> {
+        let url = format!("{host}/v1/peers", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(connect_peer_request)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a DELETE request to disconnect from a peer.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - A reference to the hex encoded public key of the peer.
+    ///
+    pub async fn disconnect_peer(&self, pub_key: &String) -> Result<()> {
+        let url = format!(
+            "{host}/v1/peers/{pub_key}",
+            host = self.host,
+            pub_key = pub_key
+        );
+
+        let response = self.client.delete(&url).send().await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to subscribe to peers going online and offline.
+    pub async fn subscribe_peer_events(&self) -> Result<NodeStream<PeerEvent>> {
+        let url = format!("{host}/v1/peers/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        Ok(json_lines(response))
+    }
+
+    /// Send a GET request to generate a new on-chain address.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_address_request` - A reference to a [NewAddressRequest] object containing the address type and account.
+    ///
+    pub async fn new_address(
+        &self,
+        new_address_request: &NewAddressRequest,
+    ) -> Result<NewAddressResponse> {
+        let url = format!("{host}/v1/newaddress", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(new_address_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: NewAddressResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to send coins on-chain to a single address.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_coins_request` - A reference to a [SendCoinsRequest] object containing the details of the transaction.
+    ///
+    pub async fn send_coins(
+        &self,
+        send_coins_request: &SendCoinsRequest,
+    ) -> Result<SendCoinsResponse> {
+        let url = format!("{host}/v1/transactions", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(send_coins_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: SendCoinsResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to send coins on-chain to several addresses in a single transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_many_request` - A reference to a [SendManyRequest] object containing the details of the transaction.
+    ///
+    pub async fn send_many(&self, send_many_request: &SendManyRequest) -> Result<SendManyResponse> {
+        let url = format!("{host}/v1/transactions/many", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(send_many_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: SendManyResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the unspent outputs of the on-chain wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_unspent_request` - A reference to a [ListUnspentRequest] object containing the confirmation range and account.
+    ///
+    pub async fn list_unspent(
+        &self,
+        list_unspent_request: &ListUnspentRequest,
+    ) -> Result<ListUnspentResponse> {
+        let url = format!("{host}/v1/utxos", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(list_unspent_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ListUnspentResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the on-chain transactions relevant to the wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `get_transactions_request` - A reference to a [GetTransactionsRequest] object containing the block range and account.
+    ///
+    pub async fn get_transactions(
+        &self,
+        get_transactions_request: &GetTransactionsRequest,
+    ) -> Result<TransactionDetails> {
+        let url = format!("{host}/v1/transactions", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(get_transactions_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: TransactionDetails = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to estimate the fee of an on-chain transaction to the given outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `estimate_fee_request` - A reference to a [EstimateFeeRequest] object containing the outputs and confirmation target.
+    ///
+    pub async fn estimate_fee(
+        &self,
+        estimate_fee_request: &EstimateFeeRequest,
+    ) -> Result<EstimateFeeResponse> {
+        let url = format!("{host}/v1/transactions/fee", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&estimate_fee_request.query())
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: EstimateFeeResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to estimate the fee rate needed to confirm a transaction within the given number of blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `conf_target` - The number of blocks within which the transaction should confirm, at least 2.
+    ///
+    pub async fn estimate_fee_rate(&self, conf_target: u32) -> Result<WalletEstimateFeeResponse> {
+        let url = format!(
+            "{host}/v2/wallet/estimatefee/{conf_target}",
+            host = self.host,
+            conf_target = conf_target
+        );
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: WalletEstimateFeeResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Quote the fee rate of a tier, in satoshis per virtual byte, ready to be used as `sat_per_vbyte` when sending coins.
+    ///
+    /// # Arguments
+    ///
+    /// * `tier` - The [FeeTier] to quote.
+    ///
+    pub async fn fee_rate(&self, tier: FeeTier) -> Result<u64> {
+        self.estimate_fee_rate(tier.conf_target())
+            .await?
+            .sat_per_vbyte()
+    }
+
+    /// Send a GET request to subscribe to on-chain transactions relevant to the wallet.
+    ///
+    /// The node sends a [Transaction] when it first sees it, unconfirmed, and again when it is first confirmed. Further
+    /// confirmations are not notified: once a transaction is confirmed, its confirmation depth can be derived from the
+    /// current block height.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The wallet account to filter transactions on, or `None` for all accounts.
+    ///
+    pub async fn subscribe_transactions(
+        &self,
+        account: Option<&str>,
+    ) -> Result<NodeStream<Transaction>> {
+        let url = format!("{host}/v1/transactions/subscribe", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&[("account", account)])
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        Ok(json_lines(response))
+    }
+
+    /// Send a GET request to list the open channels of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_channels_request` - A reference to a [ListChannelsRequest] object containing the filters to apply.
+    ///
+    pub async fn list_channels(
+        &self,
+        list_channels_request: &ListChannelsRequest,
+    ) -> Result<ListChannelsResponse> {
+        let url = format!("{host}/v1/channels", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(list_channels_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ListChannelsResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to list the closed channels of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `closed_channels_request` - A reference to a [ClosedChannelsRequest] object containing the kinds of closure to include, all of them if none is set.
+    ///
+    pub async fn closed_channels(
+        &self,
+        closed_channels_request: &ClosedChannelsRequest,
+    ) -> Result<ClosedChannelsResponse> {
+        let url = format!("{host}/v1/channels/closed", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(closed_channels_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ClosedChannelsResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to subscribe to channels being opened, closed, activated and deactivated.
+    pub async fn subscribe_channel_events(&self) -> Result<NodeStream<ChannelEventUpdate>> {
+        let url = format!("{host}/v1/channels/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        Ok(json_lines(response))
+    }
+
+    /// Send a GET request to retrieve the channel graph known to the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_unannounced` - Whether to include the private channels and the channels not yet announced.
+    ///
+    pub async fn describe_graph(&self, include_unannounced: bool) -> Result<ChannelGraph> {
+        let url = format!("{host}/v1/graph", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&[("include_unannounced", include_unannounced)])
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ChannelGraph = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve information about a node of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - A reference to the hex encoded public key of the node.
+    /// * `include_channels` - Whether to include the channels of the node.
+    ///
+    pub async fn get_node_info(
+        &self,
+        pub_key: &String,
+        include_channels: bool,
+    ) -> Result<NodeInfo> {
+        let url = format!(
+            "{host}/v1/graph/node/{pub_key}",
+            host = self.host,
+            pub_key = pub_key
+        );
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&[("include_channels", include_channels)])
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: NodeInfo = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve information about a channel of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `chan_id` - A reference to the short channel id of the channel.
+    ///
+    pub async fn get_chan_info(&self, chan_id: &String) -> Result<ChannelEdge> {
+        let url = format!(
+            "{host}/v1/graph/edge/{chan_id}",
+            host = self.host,
+            chan_id = chan_id
+        );
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ChannelEdge = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve statistics about the channel graph.
+    pub async fn get_network_info(&self) -> Result<NetworkInfo> {
+        let url = format!("{host}/v1/graph/info", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: NetworkInfo = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the betweenness centrality of every node of the graph.
+    pub async fn get_node_metrics(&self) -> Result<NodeMetricsResponse> {
+        let url = format!("{host}/v1/graph/nodemetrics", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(&[("types", "BETWEENNESS_CENTRALITY")])
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: NodeMetricsResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to subscribe to changes of the channel graph.
+    pub async fn subscribe_channel_graph(&self) -> Result<NodeStream<GraphTopologyUpdate>> {
+        let url = format!("{host}/v1/graph/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        Ok(json_lines(response))
+    }
+
+    /// Send a GET request to find routes paying a given amount to a destination node.
+    ///
+    /// Ignored pairs and route hints cannot be encoded as query parameters, so requests holding them are sent as a
+    /// POST request to `/v1/graph/routes` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_routes_request` - A reference to a [QueryRoutesRequest] object containing the destination, the amount and the constraints on the routes.
+    ///
+    pub async fn query_routes(
+        &self,
+        query_routes_request: &QueryRoutesRequest,
+    ) -> Result<QueryRoutesResponse> {
+        let request = if query_routes_request.needs_body() {
+            let url = format!("{host}/v1/graph/routes", host = self.host);
+
+            self.client.post(&url).json(query_routes_request)
+        } else {
+            let url = format!(
+                "{host}/v1/graph/routes/{pub_key}/{amt}",
+                host = self.host,
+                pub_key = query_routes_request.pub_key,
+                amt = query_routes_request.amt
+            );
+
+            self.client.get(&url).query(&query_routes_request.query())
+        };
+
+        let mut response = request.send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: QueryRoutesResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to attempt a payment along a given route.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_to_route_request` - A reference to a [SendToRouteRequest] object containing the payment hash and the route to use.
+    ///
+    pub async fn send_to_route(
+        &self,
+        send_to_route_request: &SendToRouteRequest,
+    ) -> Result<HTLCAttempt> {
+        let url = format!("{host}/v2/router/route/send", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(send_to_route_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: HTLCAttempt = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to build a route through a given list of hops.
+    ///
+    /// # Arguments
+    ///
+    /// * `build_route_request` - A reference to a [BuildRouteRequest] object containing the amount and the public keys of the hops.
+    ///
+    pub async fn build_route(
+        &self,
+        build_route_request: &BuildRouteRequest,
+    ) -> Result<BuildRouteResponse> {
+        let url = format!("{host}/v2/router/route", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(build_route_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: BuildRouteResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the routing fees of each channel and the fees earned over the last day, week and month.
+    pub async fn fee_report(&self) -> Result<FeeReportResponse> {
+        let url = format!("{host}/v1/fees", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: FeeReportResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to update the routing policy of all channels or of a single channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy_update_request` - A reference to a [PolicyUpdateRequest] object containing the channels to update and their new policy.
+    ///
+    pub async fn update_channel_policy(
+        &self,
+        policy_update_request: &PolicyUpdateRequest,
+    ) -> Result<PolicyUpdateResponse> {
+        let url = format!("{host}/v1/chanpolicy", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(policy_update_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: PolicyUpdateResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to retrieve a page of the payments forwarded by the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `forwarding_history_request` - A reference to a [ForwardingHistoryRequest] object containing the time window and the page to retrieve.
+    ///
+    pub async fn forwarding_history(
+        &self,
+        forwarding_history_request: &ForwardingHistoryRequest,
+    ) -> Result<ForwardingHistoryResponse> {
+        let url = format!("{host}/v1/switch", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(forwarding_history_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ForwardingHistoryResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Retrieve all the payments forwarded by the node, requesting the following pages as the previous ones are consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `forwarding_history_request` - A reference to a [ForwardingHistoryRequest] object containing the time window, the first page to retrieve and the size of the pages.
+    ///
+    pub fn forwarding_events(
+        &self,
+        forwarding_history_request: &ForwardingHistoryRequest,
+    ) -> NodeStream<ForwardingEvent> {
+        let state = (
+            self.clone(),
+            forwarding_history_request.clone(),
+            VecDeque::new(),
+            false,
+        );
+
+        let events = stream::unfold(
+            state,
+            |(node, mut request, mut events, mut done)| async move {
+                loop {
+                    if let Some(event) = events.pop_front() {
+                        return Some((Ok(event), (node, request, events, done)));
+                    }
+                    if done {
+                        return None;
+                    }
+
+                    match node.forwarding_history(&request).await {
+                        Ok(page) => {
+                            // The last page is the first one holding no event.
+                            done = page.forwarding_events.is_empty();
+                            request.index_offset = page.last_offset_index;
+                            events.extend(page.forwarding_events);
+                        }
+                        Err(err) => return Some((Err(err), (node, request, events, true))),
+                    }
+                }
+            },
+        );
+
+        Box::pin(events)
+    }
+
+    /// Send a GET request to generate a new aezeed cipher seed, to be used to create a wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `gen_seed_request` - A reference to a [GenSeedRequest] object containing the optional passphrase and entropy of the seed.
+    ///
+    pub async fn gen_seed(&self, gen_seed_request: &GenSeedRequest) -> Result<GenSeedResponse> {
+        let url = format!("{host}/v1/genseed", host = self.host);
+
+        let mut response = self.client.get(&url).query(gen_seed_request).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: GenSeedResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to create the wallet of a new node from a cipher seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `init_wallet_request` - A reference to a [InitWalletRequest] object containing the password and the cipher seed of the wallet.
+    ///
+    pub async fn init_wallet(
+        &self,
+        init_wallet_request: &InitWalletRequest,
+    ) -> Result<InitWalletResponse> {
+        let url = format!("{host}/v1/initwallet", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(init_wallet_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: InitWalletResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to unlock the wallet of the node after a restart.
+    ///
+    /// # Arguments
+    ///
+    /// * `unlock_wallet_request` - A reference to a [UnlockWalletRequest] object containing the password of the wallet.
+    ///
+    pub async fn unlock_wallet(&self, unlock_wallet_request: &UnlockWalletRequest) -> Result<()> {
+        let url = format!("{host}/v1/unlockwallet", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(unlock_wallet_request)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to change the password of the locked wallet, unlocking it.
+    ///
+    /// # Arguments
+    ///
+    /// * `change_password_request` - A reference to a [ChangePasswordRequest] object containing the current and new passwords of the wallet.
+    ///
+    pub async fn change_password(
+        &self,
+        change_password_request: &ChangePasswordRequest,
+    ) -> Result<ChangePasswordResponse> {
+        let url = format!("{host}/v1/changepassword", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(change_password_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ChangePasswordResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the current state of the node.
+    pub async fn get_state(&self) -> Result<GetStateResponse> {
+        let url = format!("{host}/v1/state", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: GetStateResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to subscribe to the changes of state of the node, starting with its current state.
+    pub async fn subscribe_state(&self) -> Result<NodeStream<SubscribeStateResponse>> {
+        let url = format!("{host}/v1/state/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        Ok(json_lines(response))
+    }
+
+    /// Wait until the node is started and ready to accept all calls, e.g. after unlocking its wallet.
+    ///
+    /// Return [NodeError::StreamClosed] if the node closes the subscription before becoming active.
+    pub async fn wait_for_server_active(&self) -> Result<()> {
+        let mut states = self.subscribe_state().await?;
+
+        while let Some(state) = states.next().await {
+            if state?.state == WalletState::SERVER_ACTIVE {
+                return Ok(());
+            }
+        }
+
+        Err(NodeError::StreamClosed)
+    }
+
+    /// Send a GET request to retrieve the payment results recorded by mission control for each pair of nodes.
+    pub async fn query_mission_control(&self) -> Result<QueryMissionControlResponse> {
+        let url = format!("{host}/v2/router/mc", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: QueryMissionControlResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to clear all the payment results recorded by mission control.
+    pub async fn reset_mission_control(&self) -> Result<()> {
+        let url = format!("{host}/v2/router/mc/reset", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&serde_json::json!({}))
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to import payment results into mission control, e.g. exported from another node.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_import_mission_control_request` - A reference to a [XImportMissionControlRequest] object containing the pair histories to import.
+    ///
+    pub async fn x_import_mission_control(
+        &self,
+        x_import_mission_control_request: &XImportMissionControlRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v2/router/x/importhistory", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(x_import_mission_control_request)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to estimate the probability of a payment succeeding from a node to another, as seen by mission control.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_node` - The base64 encoded public key of the node sending the payment.
+    /// * `to_node` - The base64 encoded public key of the node receiving the payment.
+    /// * `amt_msat` - The amount of the payment in millisatoshis.
+    ///
+    pub async fn query_probability(
+        &self,
+        from_node: &str,
+        to_node: &str,
+        amt_msat: u64,
+    ) -> Result<QueryProbabilityResponse> {
+        let url = format!(
+            "{host}/v2/router/mc/probability/{from_node}/{to_node}/{amt_msat}",
+            host = self.host,
+            from_node = url_safe_base64(from_node),
+            to_node = url_safe_base64(to_node),
+            amt_msat = amt_msat
+        );
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: QueryProbabilityResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to retrieve the configuration of mission control.
+    pub async fn get_mission_control_config(&self) -> Result<GetMissionControlConfigResponse> {
+        let url = format!("{host}/v2/router/mccfg", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: GetMissionControlConfigResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to update the configuration of mission control.
+    ///
+    /// # Arguments
+    ///
+    /// * `set_mission_control_config_request` - A reference to a [SetMissionControlConfigRequest] object containing the new configuration.
+    ///
+    pub async fn set_mission_control_config(
+        &self,
+        set_mission_control_config_request: &SetMissionControlConfigRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v2/router/mccfg", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(set_mission_control_config_request)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to subscribe to the HTLCs sent, received and forwarded by the node, as they are forwarded, settled or failed.
+    pub async fn subscribe_htlc_events(&self) -> Result<NodeStream<HtlcEvent>> {
+        let url = format!("{host}/v2/router/htlcevents", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        Ok(json_lines(response))
+    }
+
+    /// Send a POST request to open a channel with a peer, and subscribe to the updates of the opening.
+    ///
+    /// # Arguments
+    ///
+    /// * `open_channel_request` - A reference to an [OpenChannelRequest] object containing the channel parameters.
+    ///
+    pub async fn open_channel(
+        &self,
+        open_channel_request: &OpenChannelRequest,
+    ) -> Result<NodeStream<OpenStatusUpdate>> {
+        let url = format!("{host}/v1/channels/stream", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(open_channel_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        Ok(json_lines(response))
+    }
+
+    /// Send a POST request to advance the funding of a channel opened with a funding shim.
+    ///
+    /// # Arguments
+    ///
+    /// * `funding_transition_msg` - A reference to a [FundingTransitionMsg] object containing the funding step.
+    ///
+    pub async fn funding_state_step(
+        &self,
+        funding_transition_msg: &FundingTransitionMsg,
+    ) -> Result<()> {
+        let url = format!("{host}/v1/funding/step", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(funding_transition_msg)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to fund a PSBT with inputs of the wallet, adding a change output if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `fund_psbt_request` - A reference to a [FundPsbtRequest] object containing the PSBT or the outputs to fund.
+    ///
+    pub async fn fund_psbt(&self, fund_psbt_request: &FundPsbtRequest) -> Result<FundPsbtResponse> {
+        let url = format!("{host}/v2/wallet/psbt/fund", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(fund_psbt_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: FundPsbtResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to sign the inputs of a PSBT belonging to the wallet, without finalizing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_psbt_request` - A reference to a [SignPsbtRequest] object containing the PSBT to sign.
+    ///
+    pub async fn sign_psbt(&self, sign_psbt_request: &SignPsbtRequest) -> Result<SignPsbtResponse> {
+        let url = format!("{host}/v2/wallet/psbt/sign", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(sign_psbt_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: SignPsbtResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to sign and finalize a PSBT whose inputs all belong to the wallet, returning the final transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `finalize_psbt_request` - A reference to a [FinalizePsbtRequest] object containing the PSBT to finalize.
+    ///
+    pub async fn finalize_psbt(
+        &self,
+        finalize_psbt_request: &FinalizePsbtRequest,
+    ) -> Result<FinalizePsbtResponse> {
+        let url = format!("{host}/v2/wallet/psbt/finalize", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(finalize_psbt_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: FinalizePsbtResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to publish a signed transaction to the network.
+    ///
+    /// # Arguments
+    ///
+    /// * `publish_transaction_request` - A reference to a [PublishTransactionRequest] object containing the raw transaction and its label.
+    ///
+    pub async fn publish_transaction(
+        &self,
+        publish_transaction_request: &PublishTransactionRequest,
+    ) -> Result<PublishResponse> {
+        let url = format!("{host}/v2/wallet/tx", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(publish_transaction_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: PublishResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to list the wallet outputs currently leased.
+    pub async fn list_leases(&self) -> Result<ListLeasesResponse> {
+        let url = format!("{host}/v2/wallet/utxos/leases", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(&serde_json::json!({}))
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ListLeasesResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to lease a wallet output, so it is not selected by other transactions until the lease expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `lease_output_request` - A reference to a [LeaseOutputRequest] object containing the output, the lease id and its duration.
+    ///
+    pub async fn lease_output(
+        &self,
+        lease_output_request: &LeaseOutputRequest,
+    ) -> Result<LeaseOutputResponse> {
+        let url = format!("{host}/v2/wallet/utxos/lease", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(lease_output_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: LeaseOutputResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to release a leased wallet output before its lease expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `release_output_request` - A reference to a [ReleaseOutputRequest] object containing the output and the lease id.
+    ///
+    pub async fn release_output(
+        &self,
+        release_output_request: &ReleaseOutputRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v2/wallet/utxos/release", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(release_output_request)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to bump the fee of an unconfirmed transaction, by spending one of its outputs belonging to the wallet with a higher fee (CPFP).
+    ///
+    /// # Arguments
+    ///
+    /// * `bump_fee_request` - A reference to a [BumpFeeRequest] object containing the output to spend and the fee to target.
+    ///
+    pub async fn bump_fee(&self, bump_fee_request: &BumpFeeRequest) -> Result<()> {
+        let url = format!("{host}/v2/wallet/bumpfee", host = self.host);
+
+        let response = self.client.post(&url).json(bump_fee_request).send().await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to list the outputs the node is currently trying to sweep.
+    pub async fn pending_sweeps(&self) -> Result<PendingSweepsResponse> {
+        let url = format!("{host}/v2/wallet/sweeps/pending", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: PendingSweepsResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to list the transactions published by the node to sweep its outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_sweeps_request` - A reference to a [ListSweepsRequest] object containing the verbosity and the start height.
+    ///
+    pub async fn list_sweeps(
+        &self,
+        list_sweeps_request: &ListSweepsRequest,
+    ) -> Result<ListSweepsResponse> {
+        let url = format!("{host}/v2/wallet/sweeps", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(list_sweeps_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ListSweepsResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to list the accounts of the wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_accounts_request` - A reference to a [ListAccountsRequest] object containing the optional name and address type to filter on.
+    ///
+    pub async fn list_accounts(
+        &self,
+        list_accounts_request: &ListAccountsRequest,
+    ) -> Result<ListAccountsResponse> {
+        let url = format!("{host}/v2/wallet/accounts", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(list_accounts_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ListAccountsResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to import a watch-only account from its extended public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `import_account_request` - A reference to an [ImportAccountRequest] object containing the extended public key and the account name.
+    ///
+    pub async fn import_account(
+        &self,
+        import_account_request: &ImportAccountRequest,
+    ) -> Result<ImportAccountResponse> {
+        let url = format!("{host}/v2/wallet/accounts/import", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(import_account_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ImportAccountResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to import a public key, so the wallet watches the address derived from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `import_public_key_request` - A reference to an [ImportPublicKeyRequest] object containing the public key and its address type.
+    ///
+    pub async fn import_public_key(
+        &self,
+        import_public_key_request: &ImportPublicKeyRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v2/wallet/key/import", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(import_public_key_request)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to derive the public key at the given key locator.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_locator` - A reference to a [KeyLocator] object containing the key family and index.
+    ///
+    pub async fn derive_key(&self, key_locator: &KeyLocator) -> Result<KeyDescriptor> {
+        let url = format!("{host}/v2/wallet/key", host = self.host);
+
+        let mut response = self.client.post(&url).json(key_locator).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: KeyDescriptor = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to derive the next unused public key of a key family.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_req` - A reference to a [KeyReq] object containing the key family.
+    ///
+    pub async fn derive_next_key(&self, key_req: &KeyReq) -> Result<KeyDescriptor> {
+        let url = format!("{host}/v2/wallet/key/next", host = self.host);
+
+        let mut response = self.client.post(&url).json(key_req).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: KeyDescriptor = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to list the addresses of the wallet accounts, with their balance.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_addresses_request` - A reference to a [ListAddressesRequest] object containing the optional account name to filter on.
+    ///
+    pub async fn list_addresses(
+        &self,
+        list_addresses_request: &ListAddressesRequest,
+    ) -> Result<ListAddressesResponse> {
+        let url = format!("{host}/v2/wallet/addresses", host = self.host);
+
+        let mut response = self
+            .client
+            .get(&url)
+            .query(list_addresses_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ListAddressesResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to sign a message with the identity key of the node, returning a zbase32 encoded signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_message_request` - A reference to a [SignMessageRequest] object containing the message to sign.
+    ///
+    pub async fn sign_message(
+        &self,
+        sign_message_request: &SignMessageRequest,
+    ) -> Result<SignMessageResponse> {
+        let url = format!("{host}/v1/signmessage", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(sign_message_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: SignMessageResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to verify the zbase32 encoded signature of a message, and recover the public key of the signing node.
+    ///
+    /// # Arguments
+    ///
+    /// * `verify_message_request` - A reference to a [VerifyMessageRequest] object containing the message and its signature.
+    ///
+    pub async fn verify_message(
+        &self,
+        verify_message_request: &VerifyMessageRequest,
+    ) -> Result<VerifyMessageResponse> {
+        let url = format!("{host}/v1/verifymessage", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(verify_message_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: VerifyMessageResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to sign a message with a key of the wallet, identified by its key locator.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_message_req` - A reference to a [SignMessageReq] object containing the message and the key locator.
+    ///
+    pub async fn sign_message_with_key_locator(
+        &self,
+        sign_message_req: &SignMessageReq,
+    ) -> Result<SignMessageResp> {
+        let url = format!("{host}/v2/signer/signmessage", host = self.host);
+
+        let mut response = self.client.post(&url).json(sign_message_req).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: SignMessageResp = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to verify the signature of a message against a public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `verify_message_req` - A reference to a [VerifyMessageReq] object containing the message, its signature and the public key.
+    ///
+    pub async fn verify_message_with_pubkey(
+        &self,
+        verify_message_req: &VerifyMessageReq,
+    ) -> Result<VerifyMessageResp> {
+        let url = format!("{host}/v2/signer/verifymessage", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(verify_message_req)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: VerifyMessageResp = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to derive the ECDH shared key between an ephemeral public key and a key of the wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `shared_key_request` - A reference to a [SharedKeyRequest] object containing the ephemeral public key.
+    ///
+    pub async fn derive_shared_key(
+        &self,
+        shared_key_request: &SharedKeyRequest,
+    ) -> Result<SharedKeyResponse> {
+        let url = format!("{host}/v2/signer/sharedkey", host = self.host);
+
+        let mut response = self
+            .client
+            .post(&url)
+            .json(shared_key_request)
+            .send()
+            .await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: SharedKeyResponse = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to export the static backup of a channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `funding_txid` - The funding transaction id of the channel, as a hex string.
+    /// * `output_index` - The index of the funding output.
+    ///
+    pub async fn export_channel_backup(
+        &self,
+        funding_txid: &str,
+        output_index: u32,
+    ) -> Result<ChannelBackup> {
+        let url = format!(
+            "{host}/v1/channels/backup/{funding_txid}/{output_index}",
+            host = self.host,
+            funding_txid = funding_txid,
+            output_index = output_index
+        );
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ChannelBackup = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a GET request to export the static backups of all the channels, one per channel and one for all of them.
+    pub async fn export_all_channel_backups(&self) -> Result<ChanBackupSnapshot> {
+        let url = format!("{host}/v1/channels/backup", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        let data: ChanBackupSnapshot = response.json().await?;
+
+        Ok(data)
+    }
+
+    /// Send a POST request to check that channel backups can be decrypted and parsed by the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `chan_backup_snapshot` - A reference to a [ChanBackupSnapshot] object containing the backups to verify.
+    ///
+    pub async fn verify_chan_backup(
+        &self,
+        chan_backup_snapshot: &ChanBackupSnapshot,
+    ) -> Result<()> {
+        let url = format!("{host}/v1/channels/backup/verify", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(chan_backup_snapshot)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a POST request to restore channels from their static backups, asking the remote peers to force close them.
+    ///
+    /// # Arguments
+    ///
+    /// * `restore_chan_backup_request` - A reference to a [RestoreChanBackupRequest] object containing the backups to restore.
+    ///
+    pub async fn restore_channel_backups(
+        &self,
+        restore_chan_backup_request: &RestoreChanBackupRequest,
+    ) -> Result<()> {
+        let url = format!("{host}/v1/channels/backup/restore", host = self.host);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(restore_chan_backup_request)
+            .send()
+            .await?;
+
+        Self::on_response(response).await?;
+
+        Ok(())
+    }
+
+    /// Send a GET request to subscribe to the channel backups, sent again each time a channel is opened or closed.
+    pub async fn subscribe_channel_backups(&self) -> Result<NodeStream<ChanBackupSnapshot>> {
+        let url = format!("{host}/v1/channels/backup/subscribe", host = self.host);
+
+        let mut response = self.client.get(&url).send().await?;
+
+        response = Self::on_response(response).await?;
+
+        Ok(json_lines(response))
+    }
+
+    /// Open a WebSocket connection to intercept the HTLCs forwarded by the node, and decide whether to resume, fail or
+    /// settle each of them.
+    ///
+    /// Every [ForwardHtlcInterceptRequest] received from the stream must be answered by sending a
+    /// [ForwardHtlcInterceptResponse] with the same circuit key into the sink. HTLCs are held until they are resolved,
+    /// or failed by the node when reaching their `auto_fail_height`. Like every request, the connection is routed
+    /// through the SOCKS5 proxy set in the [NodeOptions].
+    #[cfg(feature = "websocket")]
+    pub async fn htlc_interceptor(
+        &self,
+    ) -> Result<(
+        NodeSink<ForwardHtlcInterceptResponse>,
+        NodeStream<ForwardHtlcInterceptRequest>,
+    )> {
+        self.websocket
+            .connect(&self.host, "/v2/router/htlcinterceptor?method=POST")
+            .await
+    }
+
+    /// Open a WebSocket connection to decide whether to accept or reject each channel that peers try to open with the
+    /// node, until the connection is closed.
+    ///
+    /// The policy is called with every [ChannelAcceptRequest] and its [ChannelAcceptResponse] is sent back to the node,
+    /// with the pending channel id of the request. Requests are answered one at a time, and the node rejects the
+    /// channel if no answer is received within its `acceptortimeout`. Requests that cannot be decoded, e.g. using a
+    /// commitment type unknown to this crate, are rejected without calling the policy. Like every request, the
+    /// connection is routed through the SOCKS5 proxy set in the [NodeOptions].
+    ///
+    /// The node accepts every channel while no acceptor is connected, so callers must call this method again as soon
+    /// as it returns, whether the connection was closed or failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - An async closure returning the answer to a channel opening request.
+    ///
+    #[cfg(feature = "websocket")]
+    pub async fn channel_acceptor<F, Fut>(&self, mut policy: F) -> Result<()>
+    where
+        F: FnMut(ChannelAcceptRequest) -> Fut,
+        Fut: std::future::Future<Output = ChannelAcceptResponse>,
+    {
+        // Requests are decoded here rather than by the stream, so that a request that cannot be decoded can still be
+        // answered with its pending channel id.
+        let (mut responses, mut requests) = self
+            .websocket
+            .connect::<ChannelAcceptResponse, serde_json::Value>(
+                &self.host,
+                "/v1/channels/acceptor?method=POST",
+            )
+            .await?;
+
+        while let Some(frame) = requests.next().await {
+            let frame = match frame {
+                Ok(frame) => frame,
+                // A malformed message carries no pending channel id to answer.
+                Err(NodeError::JsonError(_)) => continue,
+                Err(err) => return Err(err),
+            };
+
+            let response = match serde_json::from_value::<ChannelAcceptRequest>(frame.clone()) {
+                Ok(request) => ChannelAcceptResponse {
+                    pending_chan_id: request.pending_chan_id.clone(),
+                    ..policy(request).await
+                },
+                Err(_) => match frame.get("pending_chan_id").and_then(|id| id.as_str()) {
+                    Some(pending_chan_id) => ChannelAcceptResponse {
+                        pending_chan_id: pending_chan_id.to_string(),
+                        ..ChannelAcceptResponse::reject("unsupported channel request")
+                    },
+                    None => continue,
+                },
+            };
+            responses.send(response).await?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,253 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::node::{Node, NodeError, Result};
+use crate::types::{
+    AddInvoiceRequest, AddInvoiceResponse, GetInfoResponse, SendPaymentSyncRequest,
+    SendPaymentSyncResponse,
+};
+
+/// Represent the strategies used to choose which node serves a read call.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ReadStrategy {
+    /// Cycle through the healthy nodes.
+    #[default]
+    RoundRobin,
+    /// Pick the healthy node with the lowest latency measured by the last health check.
+    LowestLatency,
+}
+
+/// Represent the health of a node as seen by the last health check or call.
+#[derive(Clone, Debug, Default)]
+pub struct NodeHealth {
+    /// Whether the node is reachable and synced to the chain.
+    pub healthy: bool,
+    /// The time taken by the node to answer the last health check.
+    pub latency: Option<Duration>,
+    /// The reason why the node was marked unhealthy, if any.
+    pub error: Option<String>,
+}
+
+/// A node of a [NodePool] with its last known health.
+#[derive(Debug)]
+struct PoolMember {
+    node: Node,
+    health: RwLock<NodeHealth>,
+}
+
+/// Spread calls over several Lightning Network Daemon (LND) nodes.
+///
+/// Read calls are routed to healthy nodes according to the [ReadStrategy]. Write calls are sent to the primary node,
+/// and only fail over to the other healthy nodes, in order, when the connection to a node cannot be established, so
+/// that a request is never sent twice. Nodes are considered healthy until a health check or a connection failure
+/// proves otherwise.
+///
+/// Invoices and payments only exist on the node that created them, so read calls should be kept for data every node
+/// shares, such as chain and graph information.
+#[derive(Debug)]
+pub struct NodePool {
+    members: Vec<PoolMember>,
+    strategy: ReadStrategy,
+    next: AtomicUsize,
+}
+
+impl NodePool {
+    /// Create a [NodePool] from a primary node and its fallback nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary` - The node receiving write calls.
+    /// * `others` - The other nodes, in failover order.
+    /// * `strategy` - The strategy used to route read calls.
+    ///
+    pub fn new(primary: Node, others: Vec<Node>, strategy: ReadStrategy) -> Self {
+        let members = std::iter::once(primary)
+            .chain(others)
+            .map(|node| PoolMember {
+                node,
+                health: RwLock::new(NodeHealth {
+                    healthy: true,
+                    ..Default::default()
+                }),
+            })
+            .collect();
+
+        NodePool {
+            members,
+            strategy,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Return the nodes of the pool along with their last known health, the primary node first.
+    pub fn health(&self) -> Vec<(&Node, NodeHealth)> {
+        self.members
+            .iter()
+            .map(|member| (&member.node, member.health.read().unwrap().clone()))
+            .collect()
+    }
+
+    /// Call `getinfo` on every node, record its latency, and mark unreachable or unsynced nodes as unhealthy.
+    pub async fn check_health(&self) {
+        for member in &self.members {
+            let start = Instant::now();
+            let result = member.node.get_info().await;
+            let latency = start.elapsed();
+
+            let health = match result {
+                Ok(info) if info.synced_to_chain => NodeHealth {
+                    healthy: true,
+                    latency: Some(latency),
+                    error: None,
+                },
+                Ok(_) => NodeHealth {
+                    healthy: false,
+                    latency: Some(latency),
+                    error: Some("node is not synced to chain".to_string()),
+                },
+                Err(err) => NodeHealth {
+                    healthy: false,
+                    latency: None,
+                    error: Some(err.to_string()),
+                },
+            };
+
+            *member.health.write().unwrap() = health;
+        }
+    }
+
+    /// Check the health of the nodes forever, waiting for the given interval between checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The time between two health checks.
+    ///
+    pub async fn run_health_checks(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+
+        loop {
+            ticker.tick().await;
+            self.check_health().await;
+        }
+    }
+
+    /// Return the indices of the healthy nodes in failover order.
+    fn healthy(&self) -> Vec<usize> {
+        (0..self.members.len())
+            .filter(|&i| self.members[i].health.read().unwrap().healthy)
+            .collect()
+    }
+
+    /// Return the indices of the healthy nodes in the order they should serve a read call.
+    fn read_order(&self) -> Vec<usize> {
+        let mut healthy = self.healthy();
+
+        if healthy.is_empty() {
+            return healthy;
+        }
+
+        match self.strategy {
+            ReadStrategy::RoundRobin => {
+                let start = self.next.fetch_add(1, Ordering::Relaxed) % healthy.len();
+                healthy.rotate_left(start);
+            }
+            ReadStrategy::LowestLatency => {
+                healthy.sort_by_key(|&i| {
+                    self.members[i]
+                        .health
+                        .read()
+                        .unwrap()
+                        .latency
+                        .unwrap_or(Duration::MAX)
+                });
+            }
+        }
+
+        healthy
+    }
+
+    /// Try the call on each node in turn, moving to the next one only when the connection cannot be established.
+    async fn call<'a, F, Fut, T>(&'a self, order: Vec<usize>, f: F) -> Result<T>
+    where
+        F: Fn(&'a Node) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut last_error = NodeError::NoHealthyNode;
+
+        for i in order {
+            let member = &self.members[i];
+
+            match f(&member.node).await {
+                Err(err) if err.is_connect() => {
+                    *member.health.write().unwrap() = NodeHealth {
+                        healthy: false,
+                        latency: None,
+                        error: Some(err.to_string()),
+                    };
+                    last_error = err;
+                }
+                result => return result,
+            }
+        }
+
+        Err(last_error)
+    }
+
+    /// Run a read call on a healthy node chosen according to the [ReadStrategy].
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The call to run, given the chosen node.
+    ///
+    pub async fn read<'a, F, Fut, T>(&'a self, f: F) -> Result<T>
+    where
+        F: Fn(&'a Node) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.call(self.read_order(), f).await
+    }
+
+    /// Run a write call on the primary node, or on the first healthy fallback node if it is unavailable.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The call to run, given the chosen node.
+    ///
+    pub async fn write<'a, F, Fut, T>(&'a self, f: F) -> Result<T>
+    where
+        F: Fn(&'a Node) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.call(self.healthy(), f).await
+    }
+
+    /// Add a new invoice on the primary node. See [Node::add_invoice].
+    ///
+    /// # Arguments
+    ///
+    /// * `invoice` - A reference to a [AddInvoiceRequest] object containing the details of the invoice to be added.
+    ///
+    pub async fn add_invoice(&self, invoice: &AddInvoiceRequest) -> Result<AddInvoiceResponse> {
+        self.write(|node| node.add_invoice(invoice)).await
+    }
+
+    /// Pay an invoice from the primary node. See [Node::pay_invoice].
+    ///
+    /// # Arguments
+    ///
+    /// * `payment_request` - A reference to a [SendPaymentSyncRequest] object containing the details of the payment request.
+    ///
+    pub async fn pay_invoice(
+        &self,
+        payment_request: &SendPaymentSyncRequest,
+    ) -> Result<SendPaymentSyncResponse> {
+        self.write(|node| node.pay_invoice(payment_request)).await
+    }
+
+    /// Retrieve general information from a healthy node. See [Node::get_info].
+    pub async fn get_info(&self) -> Result<GetInfoResponse> {
+        self.read(|node| node.get_info()).await
+    }
+}
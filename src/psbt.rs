@@ -0,0 +1,146 @@
+use std::future::Future;
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use futures_util::StreamExt;
+
+use crate::node::{Node, NodeError, Result};
+use crate::stream::NodeStream;
+use crate::types::{
+    Base64String, FundingPsbtFinalize, FundingPsbtVerify, FundingShim, FundingShimCancel,
+    FundingTransitionMsg, OpenChannelRequest, OpenStatusUpdate, OpenStatusUpdateKind,
+    PendingUpdate, PsbtShim, ReadyForPsbtFunding,
+};
+
+/// Open a channel funded by a PSBT built and signed outside of the node wallet, e.g. by a cold storage wallet.
+///
+/// The node is asked to open the channel, then waits for a PSBT paying the funding amount to the funding address.
+/// The funded PSBT is verified by the node before being signed, and the signed PSBT is handed back to the node, which
+/// publishes the funding transaction. If a step fails, the funding shim is canceled so the node forgets the pending
+/// channel.
+#[derive(Debug)]
+pub struct PsbtChannelOpen<'a> {
+    node: &'a Node,
+    open_channel_request: OpenChannelRequest,
+    pending_chan_id: Base64String,
+}
+
+impl<'a> PsbtChannelOpen<'a> {
+    /// Create a [PsbtChannelOpen] opening a channel with the given parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node opening the channel.
+    /// * `open_channel_request` - The channel parameters, whose funding shim is replaced by a PSBT shim.
+    /// * `pending_chan_id` - The id of the pending channel, which must be unique, e.g. 32 random bytes.
+    ///
+    pub fn new(
+        node: &'a Node,
+        open_channel_request: OpenChannelRequest,
+        pending_chan_id: [u8; 32],
+    ) -> Self {
+        let pending_chan_id = BASE64_STANDARD.encode(pending_chan_id);
+
+        let open_channel_request = OpenChannelRequest {
+            funding_shim: Some(FundingShim::PsbtShim(PsbtShim {
+                pending_chan_id: pending_chan_id.clone(),
+                ..Default::default()
+            })),
+            ..open_channel_request
+        };
+
+        PsbtChannelOpen {
+            node,
+            open_channel_request,
+            pending_chan_id,
+        }
+    }
+
+    /// Return the base64 encoded id of the pending channel.
+    pub fn pending_chan_id(&self) -> &str {
+        &self.pending_chan_id
+    }
+
+    /// Walk through the steps of the channel opening, until the funding transaction is published.
+    ///
+    /// # Arguments
+    ///
+    /// * `fund` - An async closure returning an unsigned base64 encoded PSBT paying the funding amount to the funding
+    ///   address.
+    /// * `sign` - An async closure returning the funded PSBT signed by the external signer.
+    ///
+    pub async fn run<F, FFut, S, SFut>(self, fund: F, sign: S) -> Result<PendingUpdate>
+    where
+        F: FnOnce(ReadyForPsbtFunding) -> FFut,
+        FFut: Future<Output = Result<Base64String>>,
+        S: FnOnce(Base64String) -> SFut,
+        SFut: Future<Output = Result<Base64String>>,
+    {
+        let mut updates = self.node.open_channel(&self.open_channel_request).await?;
+
+        let result = self.fund_and_sign(&mut updates, fund, sign).await;
+
+        if result.is_err() {
+            let shim_cancel = FundingTransitionMsg::ShimCancel(FundingShimCancel {
+                pending_chan_id: self.pending_chan_id.clone(),
+            });
+            // The original error is more useful than a failure to cancel.
+            let _ = self.node.funding_state_step(&shim_cancel).await;
+        }
+
+        result
+    }
+
+    async fn fund_and_sign<F, FFut, S, SFut>(
+        &self,
+        updates: &mut NodeStream<OpenStatusUpdate>,
+        fund: F,
+        sign: S,
+    ) -> Result<PendingUpdate>
+    where
+        F: FnOnce(ReadyForPsbtFunding) -> FFut,
+        FFut: Future<Output = Result<Base64String>>,
+        S: FnOnce(Base64String) -> SFut,
+        SFut: Future<Output = Result<Base64String>>,
+    {
+        let ready = match next_update(updates).await? {
+            OpenStatusUpdateKind::PsbtFund(ready) => ready,
+            update => return Err(NodeError::UnexpectedUpdate(Box::new(update))),
+        };
+
+        let funded_psbt = fund(ready).await?;
+
+        let psbt_verify = FundingTransitionMsg::PsbtVerify(FundingPsbtVerify {
+            funded_psbt: funded_psbt.clone(),
+            pending_chan_id: self.pending_chan_id.clone(),
+            skip_finalize: false,
+        });
+        self.node.funding_state_step(&psbt_verify).await?;
+
+        let signed_psbt = sign(funded_psbt).await?;
+
+        let psbt_finalize = FundingTransitionMsg::PsbtFinalize(FundingPsbtFinalize {
+            signed_psbt: Some(signed_psbt),
+            pending_chan_id: self.pending_chan_id.clone(),
+            final_raw_tx: None,
+        });
+        self.node.funding_state_step(&psbt_finalize).await?;
+
+        match next_update(updates).await? {
+            OpenStatusUpdateKind::ChanPending(pending) => Ok(pending),
+            update => Err(NodeError::UnexpectedUpdate(Box::new(update))),
+        }
+    }
+}
+
+/// Return the next update of a channel opening, or an error if the stream closed.
+///
+/// # Arguments
+///
+/// * `updates` - The stream of updates of the channel opening.
+///
+async fn next_update(updates: &mut NodeStream<OpenStatusUpdate>) -> Result<OpenStatusUpdateKind> {
+    match updates.next().await {
+        Some(update) => Ok(update?.update),
+        None => Err(NodeError::StreamClosed),
+    }
+}
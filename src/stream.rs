@@ -0,0 +1,139 @@
+use std::pin::Pin;
+
+use futures_util::stream::{self, Stream};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::node::{NodeError, Result};
+
+/// Represent a stream of messages sent by a Lightning Network Daemon (LND) node, as returned by the subscription
+/// methods of [Node](crate::node::Node).
+pub type NodeStream<T> = Pin<Box<dyn Stream<Item = Result<T>> + Send>>;
+
+/// The error sent by the node in place of a message, or as the body of a failed response.
+#[derive(Debug, Deserialize)]
+pub(crate) struct RpcError {
+    code: i32,
+    message: String,
+}
+
+impl From<RpcError> for NodeError {
+    fn from(error: RpcError) -> Self {
+        NodeError::ApiError {
+            code: error.code,
+            message: error.message,
+        }
+    }
+}
+
+/// A single line of a streaming response, holding either a message or an error.
+#[derive(Debug, Deserialize)]
+struct StreamFrame<T> {
+    result: Option<T>,
+    error: Option<RpcError>,
+}
+
+/// Parse a line of a streaming response.
+///
+/// # Arguments
+///
+/// * `line` - The line to parse, without its trailing newline.
+///
+pub(crate) fn parse_frame<T: DeserializeOwned>(line: &[u8]) -> Result<T> {
+    let frame: StreamFrame<T> = serde_json::from_slice(line)?;
+
+    match frame {
+        StreamFrame {
+            error: Some(error), ..
+        } => Err(error.into()),
+        StreamFrame {
+            result: Some(result),
+            ..
+        } => Ok(result),
+        _ => Err(NodeError::EmptyFrame),
+    }
+}
+
+/// Turn a streaming response, made of one JSON object per line, into a stream of messages.
+///
+/// # Arguments
+///
+/// * `response` - An object representing the HTTP response.
+///
+pub(crate) fn json_lines<T>(response: reqwest::Response) -> NodeStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let lines = stream::unfold(
+        (Some(response), Vec::new()),
+        |(mut response, mut buffer)| async move {
+            loop {
+                if let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+                    let line: Vec<u8> = buffer.drain(..=pos).collect();
+                    if line.iter().all(u8::is_ascii_whitespace) {
+                        continue;
+                    }
+                    return Some((parse_frame(&line), (response, buffer)));
+                }
+
+                match response.as_mut()?.chunk().await {
+                    Ok(Some(chunk)) => buffer.extend_from_slice(&chunk),
+                    Ok(None) => {
+                        // The node closed the stream, flush the last line if it was not terminated.
+                        let line = std::mem::take(&mut buffer);
+                        if line.iter().all(u8::is_ascii_whitespace) {
+                            return None;
+                        }
+                        return Some((parse_frame(&line), (None, buffer)));
+                    }
+                    Err(err) => return Some((Err(err.into()), (None, buffer))),
+                }
+            }
+        },
+    );
+
+    Box::pin(lines)
+}
+
+/// Represent a stream of messages sent by a Lightning Network Daemon (LND) node, as returned by the subscription
+/// methods of [BlockingNode](crate::blocking::BlockingNode).
+///
+/// Each call to [Iterator::next] blocks until the node sends a message or closes the stream.
+#[cfg(feature = "blocking")]
+pub struct BlockingStream<T> {
+    lines: std::io::Lines<std::io::BufReader<reqwest::blocking::Response>>,
+    message: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "blocking")]
+impl<T> BlockingStream<T> {
+    /// Read a streaming response, made of one JSON object per line, as an iterator of messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - An object representing the HTTP response.
+    ///
+    pub(crate) fn new(response: reqwest::blocking::Response) -> Self {
+        use std::io::BufRead;
+
+        BlockingStream {
+            lines: std::io::BufReader::new(response).lines(),
+            message: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T: DeserializeOwned> Iterator for BlockingStream<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.lines.next()? {
+                Ok(line) if line.trim().is_empty() => continue,
+                Ok(line) => return Some(parse_frame(line.as_bytes())),
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use rustls::client::{ServerCertVerified, ServerCertVerifier};
+use rustls::{Certificate, CertificateError, ClientConfig, RootCertStore, ServerName};
+use sha2::{Digest, Sha256};
+
+use crate::node::{NodeError, Result};
+
+/// Represent the SHA-256 fingerprint of a certificate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct CertFingerprint([u8; 32]);
+
+impl CertFingerprint {
+    /// Parse a fingerprint written as 64 hexadecimal digits, optionally separated by colons as printed by
+    /// `openssl x509 -noout -fingerprint -sha256`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fingerprint` - The fingerprint to parse.
+    ///
+    pub(crate) fn parse(fingerprint: &str) -> Result<Self> {
+        let digits: Vec<u8> = fingerprint.bytes().filter(|&b| b != b':').collect();
+
+        if digits.len() != 64 {
+            return Err(NodeError::InvalidCertFingerprint(fingerprint.to_string()));
+        }
+
+        let mut bytes = [0u8; 32];
+        for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+            let pair = std::str::from_utf8(pair)
+                .map_err(|_| NodeError::InvalidCertFingerprint(fingerprint.to_string()))?;
+            *byte = u8::from_str_radix(pair, 16)
+                .map_err(|_| NodeError::InvalidCertFingerprint(fingerprint.to_string()))?;
+        }
+
+        Ok(CertFingerprint(bytes))
+    }
+
+    /// Compute the fingerprint of a DER encoded certificate.
+    fn of(cert: &Certificate) -> Self {
+        CertFingerprint(Sha256::digest(&cert.0).into())
+    }
+}
+
+impl fmt::Display for CertFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex: Vec<String> = self.0.iter().map(|b| format!("{:02X}", b)).collect();
+        write!(f, "{}", hex.join(":"))
+    }
+}
+
+/// The error reported by [PinnedCertVerifier] when the server presents an unexpected certificate.
+#[derive(Debug)]
+pub(crate) struct FingerprintMismatch {
+    pub(crate) expected: CertFingerprint,
+    pub(crate) found: CertFingerprint,
+}
+
+impl fmt::Display for FingerprintMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "certificate fingerprint {} does not match pinned fingerprint {}",
+            self.found, self.expected
+        )
+    }
+}
+
+impl StdError for FingerprintMismatch {}
+
+/// Accept the server certificate if and only if its SHA-256 fingerprint matches the pinned one.
+///
+/// The certificate chain, host name and validity period are deliberately ignored: LND generates a self-signed
+/// certificate whose names rarely match the address used to reach the node.
+struct PinnedCertVerifier {
+    fingerprint: CertFingerprint,
+}
+
+impl ServerCertVerifier for PinnedCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        _intermediates: &[Certificate],
+        _server_name: &ServerName,
+        _scts: &mut dyn Iterator<Item = &[u8]>,
+        _ocsp_response: &[u8],
+        _now: SystemTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        let found = CertFingerprint::of(end_entity);
+
+        if found == self.fingerprint {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(CertificateError::Other(Arc::new(FingerprintMismatch {
+                expected: self.fingerprint,
+                found,
+            }))
+            .into())
+        }
+    }
+}
+
+/// Build a TLS configuration that only trusts the certificate with the given fingerprint.
+///
+/// # Arguments
+///
+/// * `fingerprint` - The fingerprint of the pinned certificate.
+///
+pub(crate) fn pinned_config(fingerprint: CertFingerprint) -> ClientConfig {
+    let mut config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(RootCertStore::empty())
+        .with_no_client_auth();
+
+    config
+        .dangerous()
+        .set_certificate_verifier(Arc::new(PinnedCertVerifier { fingerprint }));
+
+    config
+}
+
+/// Look for a [FingerprintMismatch] in the chain of errors that caused a request to fail.
+///
+/// # Arguments
+///
+/// * `err` - The error returned by the HTTP client.
+///
+pub(crate) fn find_fingerprint_mismatch<'a>(
+    err: &'a (dyn StdError + 'static),
+) -> Option<&'a FingerprintMismatch> {
+    let mut source = Some(err);
+
+    while let Some(err) = source {
+        if let Some(rustls::Error::InvalidCertificate(CertificateError::Other(other))) =
+            err.downcast_ref::<rustls::Error>()
+        {
+            return other.downcast_ref::<FingerprintMismatch>();
+        }
+
+        // I/O errors do not report the error they wrap as their source.
+        source = match err.downcast_ref::<std::io::Error>() {
+            Some(io_err) => io_err
+                .get_ref()
+                .map(|inner| inner as &(dyn StdError + 'static)),
+            None => err.source(),
+        };
+    }
+
+    None
+}
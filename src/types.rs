@@ -1,7 +1,11 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 
+use base64::prelude::{Engine, BASE64_STANDARD};
 use serde::{Deserialize, Serialize};
 
+use crate::node::NodeError;
+
 /// Represent a base64 encoded string.
 pub type Base64String = String;
 
@@ -63,8 +67,9 @@ pub enum HTLCStatus {
 }
 
 /// Represent the possible failure reasons of an HTLCAttempt.
-#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[allow(nonstandard_style)]
+#[non_exhaustive]
 pub enum FailureCode {
     /// Reserved failure reason.
     RESERVED = 0,
@@ -122,6 +127,9 @@ pub enum FailureCode {
     UNKNOWN_FAILURE = 998,
     /// An unreadable failure result is returned if the received failure message cannot be decrypted.
     UNREADABLE_FAILURE = 999,
+    /// A failure reason added in a later LND version and unknown to this crate. It is never accepted by the node.
+    #[serde(other)]
+    Unknown,
 }
 
 /// See [LND API documentation](https://api.lightning.community/api/lnd/lightning/add-invoice#lnrpcinvoice).
@@ -188,14 +196,14 @@ pub struct SendPaymentSyncRequest {
 }
 
 /// See [LND API documentation](https://api.lightning.community/api/lnd/lightning/send-payment-sync#lnrpcmpprecord).
-#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct MppRecord {
     pub payment_addr: Base64String,
     pub total_amt_msat: String,
 }
 
 /// See [LND API documentation](https://api.lightning.community/api/lnd/lightning/send-payment-sync#lnrpcamprecord).
-#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct AmpRecord {
     pub root_share: Base64String,
     pub set_id: Base64String,
@@ -203,7 +211,7 @@ pub struct AmpRecord {
 }
 
 /// See [LND API documentation](https://api.lightning.community/api/lnd/lightning/send-payment-sync#lnrpchop).
-#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Hop {
     pub chan_id: String,
     pub chan_capacity: String,
@@ -212,16 +220,19 @@ pub struct Hop {
     pub expiry: i64,
     pub amt_to_forward_msat: String,
     pub fee_msat: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pub_key: Option<String>,
     pub tlv_payload: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpp_record: Option<MppRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub amp_record: Option<AmpRecord>,
     pub custom_records: HashMap<String, String>,
     pub metadata: Base64String,
 }
 
 /// See [LND API documentation](https://api.lightning.community/api/lnd/lightning/send-payment-sync#lnrpcroute).
-#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Route {
     pub total_time_lock: i64,
     pub total_amt: String,
@@ -319,3 +330,2238 @@ pub struct ListPaymentsResponse {
     pub last_index_offset: String,
     pub total_num_payments: String,
 }
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-info#lnrpcchain).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct Chain {
+    pub chain: String,
+    pub network: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-info#lnrpcfeature).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct Feature {
+    pub name: String,
+    pub is_required: bool,
+    pub is_known: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-info#lnrpcgetinforesponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetInfoResponse {
+    pub version: String,
+    pub commit_hash: String,
+    pub identity_pubkey: String,
+    pub alias: String,
+    pub color: String,
+    pub num_pending_channels: u32,
+    pub num_active_channels: u32,
+    pub num_inactive_channels: u32,
+    pub num_peers: u32,
+    pub block_height: u32,
+    pub block_hash: String,
+    pub best_header_timestamp: String,
+    pub synced_to_chain: bool,
+    pub synced_to_graph: bool,
+    pub chains: Vec<Chain>,
+    pub uris: Vec<String>,
+    pub features: HashMap<String, Feature>,
+}
+
+/// Represent the possible gossip sync types of a peer.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[allow(nonstandard_style)]
+pub enum SyncType {
+    /// Denotes that we cannot determine the peer's current sync type.
+    UNKNOWN_SYNC = 0,
+    /// Denotes that we are actively receiving new graph updates from the peer.
+    ACTIVE_SYNC = 1,
+    /// Denotes that we are not receiving new graph updates from the peer.
+    PASSIVE_SYNC = 2,
+    /// Denotes that this peer is pinned into an active sync.
+    PINNED_SYNC = 3,
+}
+
+/// Represent the possible types of a peer event.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[allow(nonstandard_style)]
+pub enum PeerEventType {
+    /// The peer came online.
+    PEER_ONLINE = 0,
+    /// The peer went offline.
+    PEER_OFFLINE = 1,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-peers#lnrpctimestampederror).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct TimestampedError {
+    pub timestamp: String,
+    pub error: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-peers#lnrpcpeer).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct Peer {
+    pub pub_key: String,
+    pub address: String,
+    pub bytes_sent: String,
+    pub bytes_recv: String,
+    pub sat_sent: String,
+    pub sat_recv: String,
+    pub inbound: bool,
+    pub ping_time: String,
+    pub sync_type: SyncType,
+    pub features: HashMap<String, Feature>,
+    pub errors: Vec<TimestampedError>,
+    pub flap_count: i32,
+    pub last_flap_ns: String,
+    pub last_ping_payload: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-peers#lnrpclistpeersresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ListPeersResponse {
+    pub peers: Vec<Peer>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/connect-peer#lnrpclightningaddress).
+#[derive(Debug, Default, Serialize, Eq, PartialEq, Clone)]
+pub struct LightningAddress {
+    pub pubkey: String,
+    pub host: String,
+}
+
+impl FromStr for LightningAddress {
+    type Err = NodeError;
+
+    /// Parse an address written as `pubkey@host`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('@') {
+            Some((pubkey, host)) if !pubkey.is_empty() && !host.is_empty() => {
+                Ok(LightningAddress {
+                    pubkey: pubkey.to_string(),
+                    host: host.to_string(),
+                })
+            }
+            _ => Err(NodeError::InvalidLightningAddress(s.to_string())),
+        }
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/connect-peer#lnrpcconnectpeerrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ConnectPeerRequest {
+    pub addr: LightningAddress,
+    pub perm: bool,
+    pub timeout: u64,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-peer-events#lnrpcpeerevent).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct PeerEvent {
+    pub pub_key: String,
+    #[serde(rename = "type")]
+    pub event_type: PeerEventType,
+}
+
+/// Represent the possible address types of the on-chain wallet.
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[allow(nonstandard_style)]
+pub enum AddressType {
+    /// Native SegWit (P2WKH) address.
+    #[default]
+    WITNESS_PUBKEY_HASH = 0,
+    /// Nested SegWit (NP2WKH) address.
+    NESTED_PUBKEY_HASH = 1,
+    /// Unused native SegWit (P2WKH) address.
+    UNUSED_WITNESS_PUBKEY_HASH = 2,
+    /// Unused nested SegWit (NP2WKH) address.
+    UNUSED_NESTED_PUBKEY_HASH = 3,
+    /// Taproot (P2TR) address.
+    TAPROOT_PUBKEY = 4,
+    /// Unused taproot (P2TR) address.
+    UNUSED_TAPROOT_PUBKEY = 5,
+}
+
+/// Represent the possible script types of a transaction output.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[allow(nonstandard_style)]
+pub enum OutputScriptType {
+    /// Pay to public key hash.
+    SCRIPT_TYPE_PUBKEY_HASH = 0,
+    /// Pay to script hash.
+    SCRIPT_TYPE_SCRIPT_HASH = 1,
+    /// Pay to witness version 0 public key hash.
+    SCRIPT_TYPE_WITNESS_V0_PUBKEY_HASH = 2,
+    /// Pay to witness version 0 script hash.
+    SCRIPT_TYPE_WITNESS_V0_SCRIPT_HASH = 3,
+    /// Pay to public key.
+    SCRIPT_TYPE_PUBKEY = 4,
+    /// Bare multisig.
+    SCRIPT_TYPE_MULTISIG = 5,
+    /// Data carrier (OP_RETURN).
+    SCRIPT_TYPE_NULLDATA = 6,
+    /// Non-standard script.
+    SCRIPT_TYPE_NON_STANDARD = 7,
+    /// Unknown witness version.
+    SCRIPT_TYPE_WITNESS_UNKNOWN = 8,
+    /// Pay to witness version 1 (taproot).
+    SCRIPT_TYPE_WITNESS_V1_TAPROOT = 9,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/new-address#lnrpcnewaddressrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct NewAddressRequest {
+    #[serde(rename = "type")]
+    pub address_type: AddressType,
+    pub account: Option<String>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/new-address#lnrpcnewaddressresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct NewAddressResponse {
+    pub address: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/send-coins#lnrpcsendcoinsrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct SendCoinsRequest {
+    pub addr: String,
+    pub amount: i64,
+    pub target_conf: Option<i32>,
+    pub sat_per_vbyte: Option<u64>,
+    pub send_all: bool,
+    pub label: Option<String>,
+    pub min_confs: Option<i32>,
+    pub spend_unconfirmed: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/send-coins#lnrpcsendcoinsresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct SendCoinsResponse {
+    pub txid: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/send-many#lnrpcsendmanyrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct SendManyRequest {
+    #[serde(rename = "AddrToAmount")]
+    pub addr_to_amount: HashMap<String, i64>,
+    pub target_conf: Option<i32>,
+    pub sat_per_vbyte: Option<u64>,
+    pub label: Option<String>,
+    pub min_confs: Option<i32>,
+    pub spend_unconfirmed: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/send-many#lnrpcsendmanyresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct SendManyResponse {
+    pub txid: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-unspent#lnrpclistunspentrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ListUnspentRequest {
+    pub min_confs: i32,
+    pub max_confs: i32,
+    pub account: Option<String>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-unspent#lnrpcoutpoint).
+///
+/// When used in a request, the transaction id can be set either as bytes or as a string.
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct OutPoint {
+    pub txid_bytes: Base64String,
+    pub txid_str: String,
+    pub output_index: u32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-unspent#lnrpcutxo).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct Utxo {
+    pub address_type: AddressType,
+    pub address: String,
+    pub amount_sat: String,
+    pub pk_script: String,
+    pub outpoint: OutPoint,
+    pub confirmations: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-unspent#lnrpclistunspentresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ListUnspentResponse {
+    pub utxos: Vec<Utxo>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-transactions#lnrpcgettransactionsrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct GetTransactionsRequest {
+    pub start_height: i32,
+    pub end_height: i32,
+    pub account: Option<String>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-transactions#lnrpcoutputdetail).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct OutputDetail {
+    pub output_type: OutputScriptType,
+    pub address: String,
+    pub pk_script: String,
+    pub output_index: String,
+    pub amount: String,
+    pub is_our_address: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-transactions#lnrpcpreviousoutpoint).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct PreviousOutPoint {
+    pub outpoint: String,
+    pub is_our_output: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-transactions#lnrpctransaction).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct Transaction {
+    pub tx_hash: String,
+    pub amount: String,
+    pub num_confirmations: i32,
+    pub block_hash: String,
+    pub block_height: i32,
+    pub time_stamp: String,
+    pub total_fees: String,
+    pub dest_addresses: Vec<String>,
+    pub output_details: Vec<OutputDetail>,
+    pub raw_tx_hex: String,
+    pub label: String,
+    pub previous_outpoints: Vec<PreviousOutPoint>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-transactions#lnrpctransactiondetails).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct TransactionDetails {
+    pub transactions: Vec<Transaction>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/estimate-fee#lnrpcestimatefeerequest).
+#[derive(Debug, Default, Serialize)]
+pub struct EstimateFeeRequest {
+    #[serde(rename = "AddrToAmount")]
+    pub addr_to_amount: HashMap<String, i64>,
+    pub target_conf: i32,
+    pub min_confs: i32,
+    pub spend_unconfirmed: bool,
+}
+
+impl EstimateFeeRequest {
+    /// Return the query parameters of the request, with the map of amounts encoded as `AddrToAmount[addr]=amount`.
+    pub(crate) fn query(&self) -> Vec<(String, String)> {
+        let mut query: Vec<(String, String)> = self
+            .addr_to_amount
+            .iter()
+            .map(|(addr, amount)| (format!("AddrToAmount[{addr}]"), amount.to_string()))
+            .collect();
+
+        query.push(("target_conf".to_string(), self.target_conf.to_string()));
+        query.push(("min_confs".to_string(), self.min_confs.to_string()));
+        query.push((
+            "spend_unconfirmed".to_string(),
+            self.spend_unconfirmed.to_string(),
+        ));
+
+        query
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/estimate-fee#lnrpcestimatefeeresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct EstimateFeeResponse {
+    pub fee_sat: String,
+    pub sat_per_vbyte: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/estimate-fee#walletrpcestimatefeeresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct WalletEstimateFeeResponse {
+    pub sat_per_kw: String,
+}
+
+impl WalletEstimateFeeResponse {
+    /// Convert the fee rate to satoshis per virtual byte, rounding up so that the transaction is not underpaid.
+    pub fn sat_per_vbyte(&self) -> Result<u64, NodeError> {
+        let sat_per_kw: u64 = self.sat_per_kw.parse()?;
+
+        // A virtual byte weighs 4 weight units, and a kw is 1000 weight units.
+        Ok((sat_per_kw * 4).div_ceil(1000).max(1))
+    }
+}
+
+/// Represent the fee rate tiers that can be quoted before sending coins on-chain.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum FeeTier {
+    /// Aim for confirmation within the next 2 blocks.
+    Fast,
+    /// Aim for confirmation within the next 6 blocks, about an hour.
+    #[default]
+    Normal,
+    /// Aim for confirmation within the next 144 blocks, about a day.
+    Economy,
+}
+
+impl FeeTier {
+    /// Return the confirmation target of the tier, in blocks.
+    pub fn conf_target(&self) -> u32 {
+        match self {
+            FeeTier::Fast => 2,
+            FeeTier::Normal => 6,
+            FeeTier::Economy => 144,
+        }
+    }
+}
+
+/// Represent the possible commitment types of a channel.
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[allow(nonstandard_style)]
+pub enum CommitmentType {
+    /// Returned when the commitment type isn't known or unavailable.
+    #[default]
+    UNKNOWN_COMMITMENT_TYPE = 0,
+    /// A channel using the legacy commitment format having tweaked to_remote keys.
+    LEGACY = 1,
+    /// A channel that uses the modern commitment format where the key in the output of the remote party does not change each state.
+    STATIC_REMOTE_KEY = 2,
+    /// A channel that uses a commitment format that has anchor outputs on the commitments.
+    ANCHORS = 3,
+    /// A channel that uses a script enforced lease, on top of anchor outputs.
+    SCRIPT_ENFORCED_LEASE = 4,
+    /// A channel that uses musig2 for the funding output and taproot for the commitment outputs.
+    SIMPLE_TAPROOT = 5,
+}
+
+/// Represent the possible ways a channel was closed.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[allow(nonstandard_style)]
+pub enum ClosureType {
+    /// The channel was cooperatively closed.
+    COOPERATIVE_CLOSE = 0,
+    /// The channel was force closed by us.
+    LOCAL_FORCE_CLOSE = 1,
+    /// The channel was force closed by the remote peer.
+    REMOTE_FORCE_CLOSE = 2,
+    /// The remote peer broadcast a revoked commitment.
+    BREACH_CLOSE = 3,
+    /// The funding transaction never confirmed.
+    FUNDING_CANCELED = 4,
+    /// The channel was abandoned.
+    ABANDONED = 5,
+}
+
+/// Represent the possible initiators of a channel open or close.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[allow(nonstandard_style)]
+pub enum Initiator {
+    /// The initiator is unknown.
+    INITIATOR_UNKNOWN = 0,
+    /// We initiated the action.
+    INITIATOR_LOCAL = 1,
+    /// The remote peer initiated the action.
+    INITIATOR_REMOTE = 2,
+    /// Both sides initiated the action.
+    INITIATOR_BOTH = 3,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-events#lnrpcchannelpoint).
+///
+/// The transaction id is set either as bytes or as a string.
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct ChannelPoint {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_txid_bytes: Option<Base64String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_txid_str: Option<String>,
+    pub output_index: u32,
+}
+
+impl ChannelPoint {
+    /// Return the funding transaction id as a hex string, as displayed by block explorers.
+    pub fn txid(&self) -> Result<String, NodeError> {
+        if let Some(txid) = &self.funding_txid_str {
+            return Ok(txid.clone());
+        }
+
+        let bytes =
+            BASE64_STANDARD.decode(self.funding_txid_bytes.as_deref().unwrap_or_default())?;
+
+        // Transaction ids are displayed in the reverse byte order.
+        Ok(bytes.iter().rev().map(|b| format!("{:02x}", b)).collect())
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-events#lnrpchtlc).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct HTLC {
+    pub incoming: bool,
+    pub amount: String,
+    pub hash_lock: Base64String,
+    pub expiration_height: u32,
+    pub htlc_index: String,
+    pub forwarding_channel: String,
+    pub forwarding_htlc_index: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-events#lnrpcchannel).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct Channel {
+    pub active: bool,
+    pub remote_pubkey: String,
+    pub channel_point: String,
+    pub chan_id: String,
+    pub capacity: String,
+    pub local_balance: String,
+    pub remote_balance: String,
+    pub commit_fee: String,
+    pub commit_weight: String,
+    pub fee_per_kw: String,
+    pub unsettled_balance: String,
+    pub total_satoshis_sent: String,
+    pub total_satoshis_received: String,
+    pub num_updates: String,
+    pub pending_htlcs: Vec<HTLC>,
+    pub csv_delay: u32,
+    pub private: bool,
+    pub initiator: bool,
+    pub chan_status_flags: String,
+    pub local_chan_reserve_sat: String,
+    pub remote_chan_reserve_sat: String,
+    pub commitment_type: CommitmentType,
+    pub lifetime: String,
+    pub uptime: String,
+    pub close_address: String,
+    pub push_amount_sat: String,
+    pub thaw_height: u32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-events#lnrpcchannelclosesummary).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ChannelCloseSummary {
+    pub channel_point: String,
+    pub chan_id: String,
+    pub chain_hash: String,
+    pub closing_tx_hash: String,
+    pub remote_pubkey: String,
+    pub capacity: String,
+    pub close_height: u32,
+    pub settled_balance: String,
+    pub time_locked_balance: String,
+    pub close_type: ClosureType,
+    pub open_initiator: Initiator,
+    pub close_initiator: Initiator,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-channels#lnrpclistchannelsrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ListChannelsRequest {
+    pub active_only: bool,
+    pub inactive_only: bool,
+    pub public_only: bool,
+    pub private_only: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/list-channels#lnrpclistchannelsresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ListChannelsResponse {
+    pub channels: Vec<Channel>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/closed-channels#lnrpcclosedchannelsrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ClosedChannelsRequest {
+    pub cooperative: bool,
+    pub local_force: bool,
+    pub remote_force: bool,
+    pub breach: bool,
+    pub funding_canceled: bool,
+    pub abandoned: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/closed-channels#lnrpcclosedchannelsresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ClosedChannelsResponse {
+    pub channels: Vec<ChannelCloseSummary>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-events#lnrpcpendingupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct PendingUpdate {
+    pub txid: Base64String,
+    pub output_index: u32,
+}
+
+/// The raw form of a [ChannelEventUpdate], holding one field per kind of update.
+#[derive(Debug, Deserialize)]
+struct RawChannelEventUpdate {
+    open_channel: Option<Channel>,
+    closed_channel: Option<ChannelCloseSummary>,
+    active_channel: Option<ChannelPoint>,
+    inactive_channel: Option<ChannelPoint>,
+    pending_open_channel: Option<PendingUpdate>,
+    fully_resolved_channel: Option<ChannelPoint>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-events#lnrpcchanneleventupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[serde(try_from = "RawChannelEventUpdate")]
+pub enum ChannelEventUpdate {
+    /// A channel was opened.
+    OpenChannel(Box<Channel>),
+    /// A channel was closed.
+    ClosedChannel(ChannelCloseSummary),
+    /// A channel became active.
+    ActiveChannel(ChannelPoint),
+    /// A channel became inactive.
+    InactiveChannel(ChannelPoint),
+    /// A channel funding transaction was broadcast.
+    PendingOpenChannel(PendingUpdate),
+    /// All the outputs of a closed channel were resolved.
+    FullyResolvedChannel(ChannelPoint),
+}
+
+impl TryFrom<RawChannelEventUpdate> for ChannelEventUpdate {
+    type Error = String;
+
+    fn try_from(raw: RawChannelEventUpdate) -> Result<Self, Self::Error> {
+        let update = if let Some(channel) = raw.open_channel {
+            ChannelEventUpdate::OpenChannel(Box::new(channel))
+        } else if let Some(summary) = raw.closed_channel {
+            ChannelEventUpdate::ClosedChannel(summary)
+        } else if let Some(point) = raw.active_channel {
+            ChannelEventUpdate::ActiveChannel(point)
+        } else if let Some(point) = raw.inactive_channel {
+            ChannelEventUpdate::InactiveChannel(point)
+        } else if let Some(update) = raw.pending_open_channel {
+            ChannelEventUpdate::PendingOpenChannel(update)
+        } else if let Some(point) = raw.fully_resolved_channel {
+            ChannelEventUpdate::FullyResolvedChannel(point)
+        } else {
+            return Err("unknown channel event update".to_string());
+        };
+
+        Ok(update)
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/describe-graph#lnrpcnodeaddress).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct NodeAddress {
+    pub network: String,
+    pub addr: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/describe-graph#lnrpclightningnode).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct LightningNode {
+    pub last_update: u32,
+    pub pub_key: String,
+    pub alias: String,
+    pub addresses: Vec<NodeAddress>,
+    pub color: String,
+    pub features: HashMap<String, Feature>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/describe-graph#lnrpcroutingpolicy).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct RoutingPolicy {
+    pub time_lock_delta: u32,
+    pub min_htlc: String,
+    pub fee_base_msat: String,
+    pub fee_rate_milli_msat: String,
+    pub disabled: bool,
+    pub max_htlc_msat: String,
+    pub last_update: u32,
+    /// Only sent by LND 0.18 and later.
+    #[serde(default)]
+    pub inbound_fee_base_msat: i32,
+    /// Only sent by LND 0.18 and later.
+    #[serde(default)]
+    pub inbound_fee_rate_milli_msat: i32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/describe-graph#lnrpcchanneledge).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ChannelEdge {
+    pub channel_id: String,
+    pub chan_point: String,
+    pub node1_pub: String,
+    pub node2_pub: String,
+    pub capacity: String,
+    pub node1_policy: Option<RoutingPolicy>,
+    pub node2_policy: Option<RoutingPolicy>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/describe-graph#lnrpcchannelgraph).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ChannelGraph {
+    pub nodes: Vec<LightningNode>,
+    pub edges: Vec<ChannelEdge>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-node-info#lnrpcnodeinfo).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct NodeInfo {
+    pub node: LightningNode,
+    pub num_channels: u32,
+    pub total_capacity: String,
+    pub channels: Vec<ChannelEdge>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-network-info#lnrpcnetworkinfo).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct NetworkInfo {
+    pub graph_diameter: u32,
+    pub avg_out_degree: f64,
+    pub max_out_degree: u32,
+    pub num_nodes: u32,
+    pub num_channels: u32,
+    pub total_network_capacity: String,
+    pub avg_channel_size: f64,
+    pub min_channel_size: String,
+    pub max_channel_size: String,
+    pub median_channel_size_sat: String,
+    pub num_zombie_chans: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-node-metrics#lnrpcfloatmetric).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct FloatMetric {
+    pub value: f64,
+    pub normalized_value: f64,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/get-node-metrics#lnrpcnodemetricsresponse).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct NodeMetricsResponse {
+    pub betweenness_centrality: HashMap<String, FloatMetric>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-graph#lnrpcnodeupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct NodeUpdate {
+    pub identity_key: String,
+    pub alias: String,
+    pub color: String,
+    pub node_addresses: Vec<NodeAddress>,
+    pub features: HashMap<String, Feature>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-graph#lnrpcchanneledgeupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ChannelEdgeUpdate {
+    pub chan_id: String,
+    pub chan_point: ChannelPoint,
+    pub capacity: String,
+    pub routing_policy: Option<RoutingPolicy>,
+    pub advertising_node: String,
+    pub connecting_node: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-graph#lnrpcclosedchannelupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ClosedChannelUpdate {
+    pub chan_id: String,
+    pub capacity: String,
+    pub closed_height: u32,
+    pub chan_point: ChannelPoint,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/subscribe-channel-graph#lnrpcgraphtopologyupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct GraphTopologyUpdate {
+    pub node_updates: Vec<NodeUpdate>,
+    pub channel_updates: Vec<ChannelEdgeUpdate>,
+    pub closed_chans: Vec<ClosedChannelUpdate>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/query-routes#lnrpcnodepair).
+#[derive(Debug, Default, Serialize, Eq, PartialEq, Clone)]
+pub struct NodePair {
+    pub from: Base64String,
+    pub to: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/query-routes#lnrpchophint).
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct HopHint {
+    pub node_id: String,
+    pub chan_id: String,
+    pub fee_base_msat: u32,
+    pub fee_proportional_millionths: u32,
+    pub cltv_expiry_delta: u32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/query-routes#lnrpcroutehint).
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct RouteHint {
+    pub hop_hints: Vec<HopHint>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/query-routes#lnrpcqueryroutesrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct QueryRoutesRequest {
+    pub pub_key: String,
+    pub amt: i64,
+    pub final_cltv_delta: Option<i32>,
+    pub fee_limit: Option<FeeLimit>,
+    pub ignored_nodes: Vec<Base64String>,
+    pub source_pub_key: Option<String>,
+    pub use_mission_control: bool,
+    pub ignored_pairs: Vec<NodePair>,
+    pub cltv_limit: Option<u32>,
+    pub outgoing_chan_id: Option<String>,
+    pub last_hop_pubkey: Option<Base64String>,
+    pub route_hints: Vec<RouteHint>,
+}
+
+impl QueryRoutesRequest {
+    /// Return the query parameters of the request, with the fee limit encoded as `fee_limit.fixed=amount` and the
+    /// ignored nodes repeated as `ignored_nodes=node`.
+    pub(crate) fn query(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+
+        if let Some(final_cltv_delta) = self.final_cltv_delta {
+            query.push(("final_cltv_delta".to_string(), final_cltv_delta.to_string()));
+        }
+        if let Some(fee_limit) = &self.fee_limit {
+            let limits = [
+                ("fixed", &fee_limit.fixed),
+                ("fixed_msat", &fee_limit.fixed_msat),
+                ("percent", &fee_limit.percent),
+            ];
+            for (name, limit) in limits {
+                if let Some(limit) = limit {
+                    query.push((format!("fee_limit.{name}"), limit.clone()));
+                }
+            }
+        }
+        for node in &self.ignored_nodes {
+            query.push(("ignored_nodes".to_string(), node.clone()));
+        }
+        if let Some(source_pub_key) = &self.source_pub_key {
+            query.push(("source_pub_key".to_string(), source_pub_key.clone()));
+        }
+        query.push((
+            "use_mission_control".to_string(),
+            self.use_mission_control.to_string(),
+        ));
+        if let Some(cltv_limit) = self.cltv_limit {
+            query.push(("cltv_limit".to_string(), cltv_limit.to_string()));
+        }
+        if let Some(outgoing_chan_id) = &self.outgoing_chan_id {
+            query.push(("outgoing_chan_id".to_string(), outgoing_chan_id.clone()));
+        }
+        if let Some(last_hop_pubkey) = &self.last_hop_pubkey {
+            query.push(("last_hop_pubkey".to_string(), last_hop_pubkey.clone()));
+        }
+
+        query
+    }
+
+    /// Whether the request holds ignored pairs or route hints, which cannot be encoded as query parameters.
+    pub(crate) fn needs_body(&self) -> bool {
+        !self.ignored_pairs.is_empty() || !self.route_hints.is_empty()
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/query-routes#lnrpcqueryroutesresponse).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct QueryRoutesResponse {
+    pub routes: Vec<Route>,
+    pub success_prob: f64,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/send-to-route-v2#routerrpcsendtorouterequest).
+#[derive(Debug, Serialize)]
+pub struct SendToRouteRequest {
+    pub payment_hash: Base64String,
+    pub route: Route,
+    pub skip_temp_err: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/build-route#routerrpcbuildrouterequest).
+#[derive(Debug, Default, Serialize)]
+pub struct BuildRouteRequest {
+    pub amt_msat: i64,
+    pub final_cltv_delta: i32,
+    pub outgoing_chan_id: Option<String>,
+    pub hop_pubkeys: Vec<Base64String>,
+    pub payment_addr: Option<Base64String>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/build-route#routerrpcbuildrouteresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct BuildRouteResponse {
+    pub route: Route,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/fee-report#lnrpcchannelfeereport).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct ChannelFeeReport {
+    pub chan_id: String,
+    pub channel_point: String,
+    pub base_fee_msat: String,
+    pub fee_per_mil: String,
+    pub fee_rate: f64,
+    /// Only sent by LND 0.18 and later.
+    #[serde(default)]
+    pub inbound_base_fee_msat: i32,
+    /// Only sent by LND 0.18 and later.
+    #[serde(default)]
+    pub inbound_fee_per_mil: i32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/fee-report#lnrpcfeereportresponse).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct FeeReportResponse {
+    pub channel_fees: Vec<ChannelFeeReport>,
+    pub day_fee_sum: String,
+    pub week_fee_sum: String,
+    pub month_fee_sum: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/update-channel-policy#lnrpcinboundfee).
+#[derive(Debug, Default, Serialize, Eq, PartialEq, Clone)]
+pub struct InboundFee {
+    pub base_fee_msat: i32,
+    pub fee_rate_ppm: i32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/update-channel-policy#lnrpcpolicyupdaterequest).
+///
+/// The policy is either applied to all channels, by setting `global`, or to the channel set in `chan_point`. The
+/// minimum HTLC is only updated when `min_htlc_msat_specified` is set, and a maximum HTLC of zero keeps the current
+/// value.
+#[derive(Debug, Default, Serialize)]
+pub struct PolicyUpdateRequest {
+    /// Not sent when unset, as it shares a oneof with `chan_point`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub global: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chan_point: Option<ChannelPoint>,
+    pub base_fee_msat: i64,
+    pub fee_rate_ppm: u32,
+    pub time_lock_delta: u32,
+    pub max_htlc_msat: u64,
+    pub min_htlc_msat: u64,
+    pub min_htlc_msat_specified: bool,
+    /// Only supported by LND 0.18 and later.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_fee: Option<InboundFee>,
+}
+
+/// Represent the possible reasons a channel policy update failed.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[allow(nonstandard_style)]
+pub enum UpdateFailure {
+    /// The reason is unknown.
+    UPDATE_FAILURE_UNKNOWN = 0,
+    /// The channel is still pending.
+    UPDATE_FAILURE_PENDING = 1,
+    /// The channel was not found.
+    UPDATE_FAILURE_NOT_FOUND = 2,
+    /// The node failed to apply the update.
+    UPDATE_FAILURE_INTERNAL_ERR = 3,
+    /// The update contains an invalid parameter.
+    UPDATE_FAILURE_INVALID_PARAMETER = 4,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/update-channel-policy#lnrpcfailedupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct FailedUpdate {
+    pub outpoint: OutPoint,
+    pub reason: UpdateFailure,
+    pub update_error: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/update-channel-policy#lnrpcpolicyupdateresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct PolicyUpdateResponse {
+    pub failed_updates: Vec<FailedUpdate>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/forwarding-history#lnrpcforwardinghistoryrequest).
+///
+/// Times are Unix timestamps in seconds. The node returns at most 100 events when `num_max_events` is zero.
+#[derive(Debug, Default, Serialize, Clone)]
+pub struct ForwardingHistoryRequest {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub index_offset: u32,
+    pub num_max_events: u32,
+    pub peer_alias_lookup: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/forwarding-history#lnrpcforwardingevent).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ForwardingEvent {
+    pub chan_id_in: String,
+    pub chan_id_out: String,
+    pub amt_in: String,
+    pub amt_out: String,
+    pub fee: String,
+    pub fee_msat: String,
+    pub amt_in_msat: String,
+    pub amt_out_msat: String,
+    pub timestamp_ns: String,
+    pub peer_alias_in: String,
+    pub peer_alias_out: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/forwarding-history#lnrpcforwardinghistoryresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ForwardingHistoryResponse {
+    pub forwarding_events: Vec<ForwardingEvent>,
+    pub last_offset_index: u32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-unlocker/gen-seed#lnrpcgenseedrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct GenSeedRequest {
+    pub aezeed_passphrase: Option<Base64String>,
+    pub seed_entropy: Option<Base64String>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-unlocker/gen-seed#lnrpcgenseedresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct GenSeedResponse {
+    pub cipher_seed_mnemonic: Vec<String>,
+    pub enciphered_seed: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-unlocker/init-wallet#lnrpcinitwalletrequest).
+///
+/// Passwords and passphrases are bytes, sent base64 encoded.
+#[derive(Debug, Default, Serialize)]
+pub struct InitWalletRequest {
+    pub wallet_password: Base64String,
+    pub cipher_seed_mnemonic: Vec<String>,
+    pub aezeed_passphrase: Option<Base64String>,
+    pub recovery_window: i32,
+    pub stateless_init: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-unlocker/init-wallet#lnrpcinitwalletresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct InitWalletResponse {
+    pub admin_macaroon: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-unlocker/unlock-wallet#lnrpcunlockwalletrequest).
+///
+/// The password is bytes, sent base64 encoded.
+#[derive(Debug, Default, Serialize)]
+pub struct UnlockWalletRequest {
+    pub wallet_password: Base64String,
+    pub recovery_window: i32,
+    pub stateless_init: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-unlocker/change-password#lnrpcchangepasswordrequest).
+///
+/// Passwords are bytes, sent base64 encoded.
+#[derive(Debug, Default, Serialize)]
+pub struct ChangePasswordRequest {
+    pub current_password: Base64String,
+    pub new_password: Base64String,
+    pub stateless_init: bool,
+    pub new_macaroon_root_key: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-unlocker/change-password#lnrpcchangepasswordresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ChangePasswordResponse {
+    pub admin_macaroon: Base64String,
+}
+
+/// Represent the possible states of the node, from the wallet being created or unlocked to the server being ready.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[allow(nonstandard_style)]
+pub enum WalletState {
+    /// The wallet has not been created yet.
+    NON_EXISTING = 0,
+    /// The wallet is locked.
+    LOCKED = 1,
+    /// The wallet was unlocked, the RPC server is not ready yet.
+    UNLOCKED = 2,
+    /// The RPC server is ready, the node is still starting.
+    RPC_ACTIVE = 3,
+    /// The node is started and ready to accept all calls.
+    SERVER_ACTIVE = 4,
+    /// The node is waiting to become the leader of a cluster before starting.
+    WAITING_TO_START = 255,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/state/get-state#lnrpcgetstateresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetStateResponse {
+    pub state: WalletState,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/state/subscribe-state#lnrpcsubscribestateresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct SubscribeStateResponse {
+    pub state: WalletState,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/query-mission-control#routerrpcpairdata).
+///
+/// Times are Unix timestamps in seconds, zero when no failure or success was recorded.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct PairData {
+    pub fail_time: String,
+    pub fail_amt_sat: String,
+    pub fail_amt_msat: String,
+    pub success_time: String,
+    pub success_amt_sat: String,
+    pub success_amt_msat: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/query-mission-control#routerrpcpairhistory).
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct PairHistory {
+    pub node_from: Base64String,
+    pub node_to: Base64String,
+    pub history: PairData,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/query-mission-control#routerrpcquerymissioncontrolresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct QueryMissionControlResponse {
+    pub pairs: Vec<PairHistory>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/x-import-mission-control#routerrpcximportmissioncontrolrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct XImportMissionControlRequest {
+    pub pairs: Vec<PairHistory>,
+    pub force: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/query-probability#routerrpcqueryprobabilityresponse).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct QueryProbabilityResponse {
+    pub probability: f64,
+    pub history: PairData,
+}
+
+/// Represent the possible models used to estimate the probability of a payment succeeding through a channel.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[allow(nonstandard_style)]
+pub enum ProbabilityModel {
+    /// Estimate the probability from a fixed prior, adjusted by past payment results.
+    #[default]
+    APRIORI = 0,
+    /// Estimate the probability from a bimodal distribution of the channel balances.
+    BIMODAL = 1,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/get-mission-control-config#routerrpcaprioriparameters).
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+pub struct AprioriParameters {
+    pub half_life_seconds: String,
+    pub hop_probability: f64,
+    pub weight: f64,
+    pub capacity_fraction: f64,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/get-mission-control-config#routerrpcbimodalparameters).
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+pub struct BimodalParameters {
+    pub node_weight: f64,
+    pub scale_msat: String,
+    pub decay_time: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/get-mission-control-config#routerrpcmissioncontrolconfig).
+///
+/// Only the parameters of the selected model are set. Supported by LND 0.16 and later.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+pub struct MissionControlConfig {
+    pub model: ProbabilityModel,
+    pub maximum_payment_results: u32,
+    pub minimum_failure_relax_interval: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apriori: Option<AprioriParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bimodal: Option<BimodalParameters>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/get-mission-control-config#routerrpcgetmissioncontrolconfigresponse).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct GetMissionControlConfigResponse {
+    pub config: MissionControlConfig,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/set-mission-control-config#routerrpcsetmissioncontrolconfigrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct SetMissionControlConfigRequest {
+    pub config: MissionControlConfig,
+}
+
+/// Represent the possible kinds of payment an HTLC is part of.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[allow(nonstandard_style)]
+pub enum HtlcEventType {
+    /// The kind of payment is unknown.
+    UNKNOWN = 0,
+    /// The HTLC is part of a payment sent by the node.
+    SEND = 1,
+    /// The HTLC is part of a payment received by the node.
+    RECEIVE = 2,
+    /// The HTLC is forwarded by the node.
+    FORWARD = 3,
+}
+
+/// Represent the possible reasons an HTLC failed, as detailed by the node on top of the wire failure code.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[allow(nonstandard_style)]
+#[non_exhaustive]
+pub enum FailureDetail {
+    /// The reason is unknown.
+    UNKNOWN = 0,
+    /// No detail is available on top of the wire failure code.
+    NO_DETAIL = 1,
+    /// The onion could not be decoded.
+    ONION_DECODE = 2,
+    /// The outgoing channel is not eligible to forward.
+    LINK_NOT_ELIGIBLE = 3,
+    /// The HTLC timed out on-chain.
+    ON_CHAIN_TIMEOUT = 4,
+    /// The HTLC exceeds the maximum HTLC of the outgoing channel.
+    HTLC_EXCEEDS_MAX = 5,
+    /// The outgoing channel does not have enough balance.
+    INSUFFICIENT_BALANCE = 6,
+    /// The HTLC was not fully forwarded before the node restarted.
+    INCOMPLETE_FORWARD = 7,
+    /// The HTLC could not be added to the outgoing channel.
+    HTLC_ADD_FAILED = 8,
+    /// Forwarding is disabled on the node.
+    FORWARDS_DISABLED = 9,
+    /// The invoice was canceled.
+    INVOICE_CANCELED = 10,
+    /// The invoice was underpaid.
+    INVOICE_UNDERPAID = 11,
+    /// The expiry of the HTLC is too soon for the invoice.
+    INVOICE_EXPIRY_TOO_SOON = 12,
+    /// The invoice is not open.
+    INVOICE_NOT_OPEN = 13,
+    /// The multi-part payment timed out.
+    MPP_INVOICE_TIMEOUT = 14,
+    /// The payment address does not match the invoice.
+    ADDRESS_MISMATCH = 15,
+    /// The total amount does not match the other parts of the payment.
+    SET_TOTAL_MISMATCH = 16,
+    /// The total amount is lower than the invoice amount.
+    SET_TOTAL_TOO_LOW = 17,
+    /// The parts of the payment exceed the invoice amount.
+    SET_OVERPAID = 18,
+    /// The invoice is unknown.
+    UNKNOWN_INVOICE = 19,
+    /// The keysend payment is invalid.
+    INVALID_KEYSEND = 20,
+    /// A multi-part payment to the invoice is in progress.
+    MPP_IN_PROGRESS = 21,
+    /// The payment would go through the incoming channel again.
+    CIRCULAR_ROUTE = 22,
+    /// A failure detail added in a later LND version and unknown to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/subscribe-htlc-events#routerrpchtlcinfo).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct HtlcInfo {
+    pub incoming_timelock: u32,
+    pub outgoing_timelock: u32,
+    pub incoming_amt_msat: String,
+    pub outgoing_amt_msat: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/subscribe-htlc-events#routerrpcforwardevent).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ForwardEvent {
+    pub info: HtlcInfo,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/subscribe-htlc-events#routerrpcsettleevent).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct SettleEvent {
+    pub preimage: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/subscribe-htlc-events#routerrpclinkfailevent).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct LinkFailEvent {
+    pub info: HtlcInfo,
+    pub wire_failure: FailureCode,
+    pub failure_detail: FailureDetail,
+    pub failure_string: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/subscribe-htlc-events#routerrpcfinalhtlcevent).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct FinalHtlcEvent {
+    pub settled: bool,
+    pub offchain: bool,
+}
+
+/// The raw form of a [HtlcEventKind], holding one field per kind of event.
+#[derive(Debug, Deserialize)]
+struct RawHtlcEventKind {
+    forward_event: Option<ForwardEvent>,
+    forward_fail_event: Option<serde::de::IgnoredAny>,
+    settle_event: Option<SettleEvent>,
+    link_fail_event: Option<LinkFailEvent>,
+    subscribed_event: Option<serde::de::IgnoredAny>,
+    final_htlc_event: Option<FinalHtlcEvent>,
+}
+
+/// Represent what happened to an HTLC, as notified by a [HtlcEvent].
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[serde(try_from = "RawHtlcEventKind")]
+pub enum HtlcEventKind {
+    /// The HTLC was forwarded to the outgoing channel.
+    Forward(ForwardEvent),
+    /// The forwarded HTLC was failed by a downstream node.
+    ForwardFail,
+    /// The HTLC was settled.
+    Settle(SettleEvent),
+    /// The HTLC was failed by the node itself.
+    LinkFail(LinkFailEvent),
+    /// The subscription is active, sent once before any other event.
+    Subscribed,
+    /// The HTLC was irrevocably settled or failed. Only sent by LND 0.16 and later.
+    FinalHtlc(FinalHtlcEvent),
+}
+
+impl TryFrom<RawHtlcEventKind> for HtlcEventKind {
+    type Error = String;
+
+    fn try_from(raw: RawHtlcEventKind) -> Result<Self, Self::Error> {
+        let kind = if let Some(event) = raw.forward_event {
+            HtlcEventKind::Forward(event)
+        } else if raw.forward_fail_event.is_some() {
+            HtlcEventKind::ForwardFail
+        } else if let Some(event) = raw.settle_event {
+            HtlcEventKind::Settle(event)
+        } else if let Some(event) = raw.link_fail_event {
+            HtlcEventKind::LinkFail(event)
+        } else if raw.subscribed_event.is_some() {
+            HtlcEventKind::Subscribed
+        } else if let Some(event) = raw.final_htlc_event {
+            HtlcEventKind::FinalHtlc(event)
+        } else {
+            return Err("unknown htlc event".to_string());
+        };
+
+        Ok(kind)
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/subscribe-htlc-events#routerrpchtlcevent).
+///
+/// Channel and HTLC ids are zero on the side of the HTLC that does not exist, e.g. the outgoing side of a payment
+/// received by the node.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct HtlcEvent {
+    pub incoming_channel_id: String,
+    pub outgoing_channel_id: String,
+    pub incoming_htlc_id: String,
+    pub outgoing_htlc_id: String,
+    pub timestamp_ns: String,
+    pub event_type: HtlcEventType,
+    #[serde(flatten)]
+    pub event: HtlcEventKind,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/htlc-interceptor#routerrpccircuitkey).
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct CircuitKey {
+    pub chan_id: String,
+    pub htlc_id: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/htlc-interceptor#routerrpcforwardhtlcinterceptrequest).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ForwardHtlcInterceptRequest {
+    pub incoming_circuit_key: CircuitKey,
+    pub incoming_amount_msat: String,
+    pub incoming_expiry: u32,
+    pub payment_hash: Base64String,
+    pub outgoing_requested_chan_id: String,
+    pub outgoing_amount_msat: String,
+    pub outgoing_expiry: u32,
+    pub custom_records: HashMap<String, Base64String>,
+    pub onion_blob: Base64String,
+    pub auto_fail_height: i32,
+}
+
+/// Represent the possible resolutions of an intercepted HTLC.
+#[derive(Debug, Default, Serialize, Eq, PartialEq, Clone, Copy)]
+#[allow(nonstandard_style)]
+pub enum ResolveHoldForwardAction {
+    /// Settle the HTLC with its preimage.
+    SETTLE = 0,
+    /// Fail the HTLC back to the incoming channel.
+    FAIL = 1,
+    /// Let the node forward the HTLC as it would without interception.
+    #[default]
+    RESUME = 2,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/router/htlc-interceptor#routerrpcforwardhtlcinterceptresponse).
+#[derive(Debug, Default, Serialize, Eq, PartialEq, Clone)]
+pub struct ForwardHtlcInterceptResponse {
+    pub incoming_circuit_key: CircuitKey,
+    pub action: ResolveHoldForwardAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preimage: Option<Base64String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_message: Option<Base64String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_code: Option<FailureCode>,
+}
+
+impl ForwardHtlcInterceptResponse {
+    /// Resume the forwarding of an intercepted HTLC.
+    ///
+    /// # Arguments
+    ///
+    /// * `incoming_circuit_key` - The key of the intercepted HTLC.
+    ///
+    pub fn resume(incoming_circuit_key: CircuitKey) -> Self {
+        ForwardHtlcInterceptResponse {
+            incoming_circuit_key,
+            action: ResolveHoldForwardAction::RESUME,
+            ..Default::default()
+        }
+    }
+
+    /// Fail an intercepted HTLC back to the incoming channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `incoming_circuit_key` - The key of the intercepted HTLC.
+    /// * `failure_code` - The failure code sent back, e.g. [FailureCode::TEMPORARY_CHANNEL_FAILURE].
+    ///
+    pub fn fail(incoming_circuit_key: CircuitKey, failure_code: FailureCode) -> Self {
+        ForwardHtlcInterceptResponse {
+            incoming_circuit_key,
+            action: ResolveHoldForwardAction::FAIL,
+            failure_code: Some(failure_code),
+            ..Default::default()
+        }
+    }
+
+    /// Settle an intercepted HTLC with its preimage.
+    ///
+    /// # Arguments
+    ///
+    /// * `incoming_circuit_key` - The key of the intercepted HTLC.
+    /// * `preimage` - The base64 encoded preimage of the payment hash.
+    ///
+    pub fn settle(incoming_circuit_key: CircuitKey, preimage: Base64String) -> Self {
+        ForwardHtlcInterceptResponse {
+            incoming_circuit_key,
+            action: ResolveHoldForwardAction::SETTLE,
+            preimage: Some(preimage),
+            ..Default::default()
+        }
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/channel-acceptor#lnrpcchannelacceptrequest).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ChannelAcceptRequest {
+    pub node_pubkey: Base64String,
+    pub chain_hash: Base64String,
+    pub pending_chan_id: Base64String,
+    pub funding_amt: String,
+    pub push_amt: String,
+    pub dust_limit: String,
+    pub max_value_in_flight: String,
+    pub channel_reserve: String,
+    pub min_htlc: String,
+    pub fee_per_kw: String,
+    pub csv_delay: u32,
+    pub max_accepted_htlcs: u32,
+    pub channel_flags: u32,
+    pub commitment_type: CommitmentType,
+    pub wants_zero_conf: bool,
+    pub wants_scid_alias: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/channel-acceptor#lnrpcchannelacceptresponse).
+#[derive(Debug, Default, Serialize, Eq, PartialEq, Clone)]
+pub struct ChannelAcceptResponse {
+    pub accept: bool,
+    pub pending_chan_id: Base64String,
+    pub error: String,
+    pub upfront_shutdown: String,
+    pub csv_delay: u32,
+    pub reserve_sat: u64,
+    pub in_flight_max_msat: u64,
+    pub max_htlc_count: u32,
+    pub min_htlc_in: u64,
+    pub min_accept_depth: u32,
+    pub zero_conf: bool,
+}
+
+impl ChannelAcceptResponse {
+    /// Accept a channel opening, with the default parameters of the node.
+    pub fn accept() -> Self {
+        ChannelAcceptResponse {
+            accept: true,
+            ..Default::default()
+        }
+    }
+
+    /// Reject a channel opening.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error message sent to the peer.
+    ///
+    pub fn reject(error: &str) -> Self {
+        ChannelAcceptResponse {
+            accept: false,
+            error: error.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/open-channel#lnrpcpsbtshim).
+#[derive(Debug, Default, Serialize, Clone)]
+pub struct PsbtShim {
+    pub pending_chan_id: Base64String,
+    pub base_psbt: Base64String,
+    pub no_publish: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/open-channel#lnrpcfundingshim).
+///
+/// Only the PSBT shim is supported.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum FundingShim {
+    /// Fund the channel with a PSBT built outside of the node wallet.
+    PsbtShim(PsbtShim),
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/open-channel#lnrpcopenchannelrequest).
+#[derive(Debug, Default, Serialize, Clone)]
+pub struct OpenChannelRequest {
+    pub sat_per_vbyte: u64,
+    pub node_pubkey: Base64String,
+    pub local_funding_amount: i64,
+    pub push_sat: i64,
+    pub target_conf: i32,
+    pub private: bool,
+    pub min_htlc_msat: i64,
+    pub remote_csv_delay: u32,
+    pub min_confs: i32,
+    pub spend_unconfirmed: bool,
+    pub close_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funding_shim: Option<FundingShim>,
+    pub remote_max_value_in_flight_msat: u64,
+    pub remote_max_htlcs: u32,
+    pub max_local_csv: u32,
+    pub commitment_type: CommitmentType,
+    pub zero_conf: bool,
+    pub scid_alias: bool,
+    pub base_fee: u64,
+    pub fee_rate: u64,
+    pub use_base_fee: bool,
+    pub use_fee_rate: bool,
+    pub remote_chan_reserve_sat: u64,
+    pub fund_max: bool,
+    pub memo: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/open-channel#lnrpcchannelopenupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ChannelOpenUpdate {
+    pub channel_point: ChannelPoint,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/open-channel#lnrpcreadyforpsbtfunding).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ReadyForPsbtFunding {
+    pub funding_address: String,
+    pub funding_amount: String,
+    pub psbt: Base64String,
+}
+
+/// The raw form of an [OpenStatusUpdateKind], holding one field per kind of update.
+#[derive(Debug, Deserialize)]
+struct RawOpenStatusUpdateKind {
+    chan_pending: Option<PendingUpdate>,
+    chan_open: Option<ChannelOpenUpdate>,
+    psbt_fund: Option<ReadyForPsbtFunding>,
+}
+
+/// Represent the possible updates of a channel being opened.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[serde(try_from = "RawOpenStatusUpdateKind")]
+pub enum OpenStatusUpdateKind {
+    /// The funding transaction was broadcast.
+    ChanPending(PendingUpdate),
+    /// The funding transaction confirmed and the channel is open.
+    ChanOpen(ChannelOpenUpdate),
+    /// The node waits for a PSBT funding the channel, as the open was requested with a [PsbtShim].
+    PsbtFund(ReadyForPsbtFunding),
+}
+
+impl TryFrom<RawOpenStatusUpdateKind> for OpenStatusUpdateKind {
+    type Error = String;
+
+    fn try_from(raw: RawOpenStatusUpdateKind) -> Result<Self, Self::Error> {
+        let kind = if let Some(update) = raw.chan_pending {
+            OpenStatusUpdateKind::ChanPending(update)
+        } else if let Some(update) = raw.chan_open {
+            OpenStatusUpdateKind::ChanOpen(update)
+        } else if let Some(update) = raw.psbt_fund {
+            OpenStatusUpdateKind::PsbtFund(update)
+        } else {
+            return Err("unknown open status update".to_string());
+        };
+
+        Ok(kind)
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/open-channel#lnrpcopenstatusupdate).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct OpenStatusUpdate {
+    pub pending_chan_id: Base64String,
+    #[serde(flatten)]
+    pub update: OpenStatusUpdateKind,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/funding-state-step#lnrpcfundingshimcancel).
+#[derive(Debug, Default, Serialize, Clone)]
+pub struct FundingShimCancel {
+    pub pending_chan_id: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/funding-state-step#lnrpcfundingpsbtverify).
+#[derive(Debug, Default, Serialize, Clone)]
+pub struct FundingPsbtVerify {
+    pub funded_psbt: Base64String,
+    pub pending_chan_id: Base64String,
+    pub skip_finalize: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/funding-state-step#lnrpcfundingpsbtfinalize).
+///
+/// Either the signed PSBT or the final raw transaction is set.
+#[derive(Debug, Default, Serialize, Clone)]
+pub struct FundingPsbtFinalize {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signed_psbt: Option<Base64String>,
+    pub pending_chan_id: Base64String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_raw_tx: Option<Base64String>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/funding-state-step#lnrpcfundingtransitionmsg).
+///
+/// Registering a channel point shim is not supported.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum FundingTransitionMsg {
+    /// Cancel a funding shim registered for a pending channel.
+    ShimCancel(FundingShimCancel),
+    /// Check that a funded PSBT pays the channel funding output, before signing it.
+    PsbtVerify(FundingPsbtVerify),
+    /// Publish the signed funding transaction and continue the channel opening.
+    PsbtFinalize(FundingPsbtFinalize),
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/fund-psbt#walletrpctxtemplate).
+#[derive(Debug, Default, Serialize, Clone)]
+pub struct TxTemplate {
+    pub inputs: Vec<OutPoint>,
+    pub outputs: HashMap<String, u64>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/fund-psbt#walletrpcfundpsbtrequest).
+///
+/// Either a PSBT or a transaction template is set, and either a confirmation target or a fee rate.
+#[derive(Debug, Default, Serialize)]
+pub struct FundPsbtRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub psbt: Option<Base64String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw: Option<TxTemplate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_conf: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sat_per_vbyte: Option<u64>,
+    pub account: String,
+    pub min_confs: i32,
+    pub spend_unconfirmed: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/fund-psbt#walletrpcutxolease).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct UtxoLease {
+    pub id: Base64String,
+    pub outpoint: OutPoint,
+    pub expiration: String,
+    pub pk_script: Base64String,
+    pub value: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/fund-psbt#walletrpcfundpsbtresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct FundPsbtResponse {
+    pub funded_psbt: Base64String,
+    pub change_output_index: i32,
+    pub locked_utxos: Vec<UtxoLease>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/sign-psbt#walletrpcsignpsbtrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct SignPsbtRequest {
+    pub funded_psbt: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/sign-psbt#walletrpcsignpsbtresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct SignPsbtResponse {
+    pub signed_psbt: Base64String,
+    pub signed_inputs: Vec<u32>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/finalize-psbt#walletrpcfinalizepsbtrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct FinalizePsbtRequest {
+    pub funded_psbt: Base64String,
+    pub account: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/finalize-psbt#walletrpcfinalizepsbtresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct FinalizePsbtResponse {
+    pub signed_psbt: Base64String,
+    pub raw_final_tx: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/publish-transaction#walletrpctransaction).
+#[derive(Debug, Default, Serialize)]
+pub struct PublishTransactionRequest {
+    pub tx_hex: Base64String,
+    pub label: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/publish-transaction#walletrpcpublishresponse).
+///
+/// The publish error is empty if the transaction was published.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct PublishResponse {
+    pub publish_error: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-leases#walletrpclistleasesresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ListLeasesResponse {
+    pub locked_utxos: Vec<UtxoLease>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/lease-output#walletrpcleaseoutputrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct LeaseOutputRequest {
+    pub id: Base64String,
+    pub outpoint: OutPoint,
+    pub expiration_seconds: u64,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/lease-output#walletrpcleaseoutputresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct LeaseOutputResponse {
+    pub expiration: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/release-output#walletrpcreleaseoutputrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ReleaseOutputRequest {
+    pub id: Base64String,
+    pub outpoint: OutPoint,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/bump-fee#walletrpcbumpfeerequest).
+///
+/// Either a confirmation target or a fee rate is set.
+#[derive(Debug, Default, Serialize)]
+pub struct BumpFeeRequest {
+    pub outpoint: OutPoint,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_conf: Option<u32>,
+    pub force: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sat_per_vbyte: Option<u64>,
+}
+
+/// Represent the possible ways an output swept by the node is spent.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[allow(nonstandard_style)]
+#[non_exhaustive]
+pub enum WitnessType {
+    /// The witness type is unknown.
+    UNKNOWN_WITNESS = 0,
+    /// Our output on our commitment, after its CSV delay.
+    COMMITMENT_TIME_LOCK = 1,
+    /// Our output on the commitment of the remote party.
+    COMMITMENT_NO_DELAY = 2,
+    /// An output of a revoked commitment of the remote party.
+    COMMITMENT_REVOKE = 3,
+    /// An offered HTLC of a revoked commitment of the remote party.
+    HTLC_OFFERED_REVOKE = 4,
+    /// An accepted HTLC of a revoked commitment of the remote party.
+    HTLC_ACCEPTED_REVOKE = 5,
+    /// An offered HTLC timing out through our second level transaction.
+    HTLC_OFFERED_TIMEOUT_SECOND_LEVEL = 6,
+    /// An accepted HTLC settled through our second level transaction.
+    HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL = 7,
+    /// An HTLC we offered, timing out on the commitment of the remote party.
+    HTLC_OFFERED_REMOTE_TIMEOUT = 8,
+    /// An HTLC we accepted, settled on the commitment of the remote party.
+    HTLC_ACCEPTED_REMOTE_SUCCESS = 9,
+    /// A second level HTLC output of a revoked commitment of the remote party.
+    HTLC_SECOND_LEVEL_REVOKE = 10,
+    /// A native SegWit (P2WKH) output.
+    WITNESS_KEY_HASH = 11,
+    /// A nested SegWit (NP2WKH) output.
+    NESTED_WITNESS_KEY_HASH = 12,
+    /// An anchor output of a commitment.
+    COMMITMENT_ANCHOR = 13,
+    /// Our output on the commitment of the remote party, using a static remote key.
+    COMMITMENT_NO_DELAY_TWEAKLESS = 14,
+    /// Our output on the commitment of the remote party, after one confirmation.
+    COMMITMENT_TO_REMOTE_CONFIRMED = 15,
+    /// An offered HTLC timing out through our second level transaction, whose input is confirmed.
+    HTLC_OFFERED_TIMEOUT_SECOND_LEVEL_INPUT_CONFIRMED = 16,
+    /// An accepted HTLC settled through our second level transaction, whose input is confirmed.
+    HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL_INPUT_CONFIRMED = 17,
+    /// Our output on our commitment of a leased channel, after its CSV delay and lease expiry.
+    LEASE_COMMITMENT_TIME_LOCK = 18,
+    /// Our output on the commitment of the remote party of a leased channel, after its lease expiry.
+    LEASE_COMMITMENT_TO_REMOTE_CONFIRMED = 19,
+    /// An offered HTLC of a leased channel timing out through our second level transaction.
+    LEASE_HTLC_OFFERED_TIMEOUT_SECOND_LEVEL = 20,
+    /// An accepted HTLC of a leased channel settled through our second level transaction.
+    LEASE_HTLC_ACCEPTED_SUCCESS_SECOND_LEVEL = 21,
+    /// A taproot (P2TR) output spent with its key.
+    TAPROOT_PUB_KEY_SPEND = 22,
+    /// A witness type added in a later LND version and unknown to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/pending-sweeps#walletrpcpendingsweep).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct PendingSweep {
+    pub outpoint: OutPoint,
+    pub witness_type: WitnessType,
+    pub amount_sat: u32,
+    pub broadcast_attempts: u32,
+    pub next_broadcast_height: u32,
+    pub requested_conf_target: u32,
+    pub force: bool,
+    pub sat_per_vbyte: String,
+    pub requested_sat_per_vbyte: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/pending-sweeps#walletrpcpendingsweepsresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct PendingSweepsResponse {
+    pub pending_sweeps: Vec<PendingSweep>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-sweeps#walletrpclistsweepsrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ListSweepsRequest {
+    pub verbose: bool,
+    pub start_height: i32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-sweeps#walletrpclistsweepsresponsetransactionids).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct TransactionIDs {
+    pub transaction_ids: Vec<String>,
+}
+
+/// The raw form of a [ListSweepsResponse], holding one field per kind of response.
+#[derive(Debug, Deserialize)]
+struct RawListSweepsResponse {
+    transaction_details: Option<TransactionDetails>,
+    transaction_ids: Option<TransactionIDs>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-sweeps#walletrpclistsweepsresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[serde(try_from = "RawListSweepsResponse")]
+pub enum ListSweepsResponse {
+    /// The sweep transactions, returned when the request is verbose.
+    TransactionDetails(TransactionDetails),
+    /// The ids of the sweep transactions.
+    TransactionIds(TransactionIDs),
+}
+
+impl TryFrom<RawListSweepsResponse> for ListSweepsResponse {
+    type Error = String;
+
+    fn try_from(raw: RawListSweepsResponse) -> Result<Self, Self::Error> {
+        let response = if let Some(details) = raw.transaction_details {
+            ListSweepsResponse::TransactionDetails(details)
+        } else if let Some(ids) = raw.transaction_ids {
+            ListSweepsResponse::TransactionIds(ids)
+        } else {
+            return Err("unknown list sweeps response".to_string());
+        };
+
+        Ok(response)
+    }
+}
+
+/// Represent the possible address types of a wallet account.
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[allow(nonstandard_style)]
+pub enum AccountAddressType {
+    /// The address type is unknown.
+    #[default]
+    UNKNOWN = 0,
+    /// Native SegWit (P2WKH) addresses, derived with BIP-0084.
+    WITNESS_PUBKEY_HASH = 1,
+    /// Nested SegWit (NP2WKH) addresses, derived with BIP-0049.
+    NESTED_WITNESS_PUBKEY_HASH = 2,
+    /// Nested SegWit (NP2WKH) external addresses and native SegWit (P2WKH) change addresses, derived with BIP-0049.
+    HYBRID_NESTED_WITNESS_PUBKEY_HASH = 3,
+    /// Taproot (P2TR) addresses, derived with BIP-0086.
+    TAPROOT_PUBKEY = 4,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-accounts#walletrpcaccount).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct Account {
+    pub name: String,
+    pub address_type: AccountAddressType,
+    pub extended_public_key: String,
+    pub master_key_fingerprint: Base64String,
+    pub derivation_path: String,
+    pub external_key_count: u32,
+    pub internal_key_count: u32,
+    pub watch_only: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-accounts#walletrpclistaccountsrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ListAccountsRequest {
+    pub name: Option<String>,
+    pub address_type: Option<AccountAddressType>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-accounts#walletrpclistaccountsresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ListAccountsResponse {
+    pub accounts: Vec<Account>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/import-account#walletrpcimportaccountrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ImportAccountRequest {
+    pub name: String,
+    pub extended_public_key: String,
+    pub master_key_fingerprint: Base64String,
+    pub address_type: AccountAddressType,
+    pub dry_run: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/import-account#walletrpcimportaccountresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ImportAccountResponse {
+    pub account: Account,
+    pub dry_run_external_addrs: Vec<String>,
+    pub dry_run_internal_addrs: Vec<String>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/import-public-key#walletrpcimportpublickeyrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ImportPublicKeyRequest {
+    pub public_key: Base64String,
+    pub address_type: AccountAddressType,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/derive-key#signrpckeylocator).
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct KeyLocator {
+    pub key_family: i32,
+    pub key_index: i32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/derive-key#signrpckeydescriptor).
+///
+/// When used in a request, the key can be set either as raw bytes or by its locator.
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct KeyDescriptor {
+    pub raw_key_bytes: Base64String,
+    pub key_loc: KeyLocator,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/derive-next-key#walletrpckeyreq).
+#[derive(Debug, Default, Serialize)]
+pub struct KeyReq {
+    pub key_finger_print: i32,
+    pub key_family: i32,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-addresses#walletrpclistaddressesrequest).
+#[derive(Debug, Default, Serialize)]
+pub struct ListAddressesRequest {
+    pub account_name: Option<String>,
+    pub show_custom_accounts: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-addresses#walletrpcaddressproperty).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct AddressProperty {
+    pub address: String,
+    pub is_internal: bool,
+    pub balance: String,
+    /// Only sent by LND 0.18 and later.
+    #[serde(default)]
+    pub derivation_path: String,
+    /// Only sent by LND 0.18 and later.
+    #[serde(default)]
+    pub public_key: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-addresses#walletrpcaccountwithaddresses).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct AccountWithAddresses {
+    pub name: String,
+    pub address_type: AccountAddressType,
+    pub derivation_path: String,
+    pub addresses: Vec<AddressProperty>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/wallet-kit/list-addresses#walletrpclistaddressesresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct ListAddressesResponse {
+    pub account_with_addresses: Vec<AccountWithAddresses>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/sign-message#lnrpcsignmessagerequest).
+#[derive(Debug, Default, Serialize)]
+pub struct SignMessageRequest {
+    pub msg: Base64String,
+    pub single_hash: bool,
+}
+
+impl SignMessageRequest {
+    /// Sign a message with the identity key of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to sign.
+    ///
+    pub fn new(msg: &[u8]) -> Self {
+        SignMessageRequest {
+            msg: BASE64_STANDARD.encode(msg),
+            ..Default::default()
+        }
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/sign-message#lnrpcsignmessageresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct SignMessageResponse {
+    /// The zbase32 encoded signature.
+    pub signature: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/verify-message#lnrpcverifymessagerequest).
+#[derive(Debug, Default, Serialize)]
+pub struct VerifyMessageRequest {
+    pub msg: Base64String,
+    pub signature: String,
+}
+
+impl VerifyMessageRequest {
+    /// Verify the signature of a message, signed by a node of the network.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The signed message.
+    /// * `signature` - The zbase32 encoded signature.
+    ///
+    pub fn new(msg: &[u8], signature: &str) -> Self {
+        VerifyMessageRequest {
+            msg: BASE64_STANDARD.encode(msg),
+            signature: signature.to_string(),
+        }
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/verify-message#lnrpcverifymessageresponse).
+///
+/// The public key is recovered from the signature, and the signature is only valid if it belongs to a node of the
+/// graph.
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct VerifyMessageResponse {
+    pub valid: bool,
+    pub pubkey: String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/signer/sign-message#signrpcsignmessagereq).
+#[derive(Debug, Default, Serialize)]
+pub struct SignMessageReq {
+    pub msg: Base64String,
+    pub key_loc: KeyLocator,
+    pub double_hash: bool,
+    pub compact_sig: bool,
+    pub schnorr_sig: bool,
+    pub schnorr_sig_tap_tweak: Base64String,
+}
+
+impl SignMessageReq {
+    /// Sign a message with the key at the given key locator, returning a DER encoded ECDSA signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message to sign.
+    /// * `key_loc` - The locator of the key to sign with.
+    ///
+    pub fn new(msg: &[u8], key_loc: KeyLocator) -> Self {
+        SignMessageReq {
+            msg: BASE64_STANDARD.encode(msg),
+            key_loc,
+            ..Default::default()
+        }
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/signer/sign-message#signrpcsignmessageresp).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct SignMessageResp {
+    pub signature: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/signer/verify-message#signrpcverifymessagereq).
+#[derive(Debug, Default, Serialize)]
+pub struct VerifyMessageReq {
+    pub msg: Base64String,
+    pub signature: Base64String,
+    pub pubkey: Base64String,
+    pub is_schnorr_sig: bool,
+}
+
+impl VerifyMessageReq {
+    /// Verify a DER encoded ECDSA signature of a message against a public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The signed message.
+    /// * `signature` - The signature.
+    /// * `pubkey` - The compressed public key of the signer.
+    ///
+    pub fn new(msg: &[u8], signature: &[u8], pubkey: &[u8]) -> Self {
+        VerifyMessageReq {
+            msg: BASE64_STANDARD.encode(msg),
+            signature: BASE64_STANDARD.encode(signature),
+            pubkey: BASE64_STANDARD.encode(pubkey),
+            ..Default::default()
+        }
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/signer/verify-message#signrpcverifymessageresp).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct VerifyMessageResp {
+    pub valid: bool,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/signer/derive-shared-key#signrpcsharedkeyrequest).
+///
+/// The identity key of the node is used when no key descriptor is set.
+#[derive(Debug, Default, Serialize)]
+pub struct SharedKeyRequest {
+    pub ephemeral_pubkey: Base64String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_desc: Option<KeyDescriptor>,
+}
+
+impl SharedKeyRequest {
+    /// Derive the ECDH shared key between an ephemeral public key and the identity key of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `ephemeral_pubkey` - The compressed public key of the other party.
+    ///
+    pub fn new(ephemeral_pubkey: &[u8]) -> Self {
+        SharedKeyRequest {
+            ephemeral_pubkey: BASE64_STANDARD.encode(ephemeral_pubkey),
+            key_desc: None,
+        }
+    }
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/signer/derive-shared-key#signrpcsharedkeyresponse).
+#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+pub struct SharedKeyResponse {
+    pub shared_key: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/export-channel-backup#lnrpcchannelbackup).
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct ChannelBackup {
+    pub chan_point: ChannelPoint,
+    pub chan_backup: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/export-all-channel-backups#lnrpcchannelbackups).
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct ChannelBackups {
+    pub chan_backups: Vec<ChannelBackup>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/export-all-channel-backups#lnrpcmultichanbackup).
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct MultiChanBackup {
+    pub chan_points: Vec<ChannelPoint>,
+    pub multi_chan_backup: Base64String,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/export-all-channel-backups#lnrpcchanbackupsnapshot).
+///
+/// Both kinds of backups are returned by the node. When verifying backups, either kind can be set.
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct ChanBackupSnapshot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_chan_backups: Option<ChannelBackups>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_chan_backup: Option<MultiChanBackup>,
+}
+
+/// See [LND API documentation](https://lightning.engineering/api-docs/api/lnd/lightning/restore-channel-backups#lnrpcrestorechanbackuprequest).
+///
+/// Either the single channel backups or the multi-channel backup is set.
+#[derive(Debug, Default, Serialize)]
+pub struct RestoreChanBackupRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chan_backups: Option<ChannelBackups>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_chan_backup: Option<Base64String>,
+}
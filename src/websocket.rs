@@ -0,0 +1,228 @@
+use std::fmt;
+use std::path::Path;
+use std::pin::Pin;
+
+use futures_util::{Sink, SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::net::TcpStream;
+use tokio_socks::tcp::Socks5Stream;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::error::TlsError;
+use tokio_tungstenite::tungstenite::{Error as WebSocketError, Message};
+use tokio_tungstenite::Connector;
+use url::Host;
+
+use crate::node::{NodeError, NodeOptions, Result};
+use crate::stream::{parse_frame, NodeStream};
+
+/// Represent the sending half of a bidirectional stream opened with a Lightning Network Daemon (LND) node, as
+/// returned by the WebSocket methods of [Node](crate::node::Node).
+pub type NodeSink<T> = Pin<Box<dyn Sink<T, Error = NodeError> + Send>>;
+
+/// The settings used to open WebSocket connections with the node, matching the ones of its HTTP client.
+#[derive(Clone)]
+pub(crate) struct WebSocketConfig {
+    headers: reqwest::header::HeaderMap,
+    connector: Option<Connector>,
+    proxy: Option<String>,
+}
+
+impl fmt::Debug for WebSocketConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebSocketConfig")
+            .field("tls", &self.connector.is_some())
+            .field("proxy", &self.proxy)
+            .finish()
+    }
+}
+
+impl WebSocketConfig {
+    /// Build the settings sending the given headers, trusting the node certificate and using the SOCKS5 proxy of the
+    /// options.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The headers to send when opening a connection.
+    /// * `cert_path` - The path to the certificate file.
+    /// * `options` - A reference to a [NodeOptions] object containing the optional settings.
+    ///
+    pub(crate) fn new<P: AsRef<Path>>(
+        headers: reqwest::header::HeaderMap,
+        cert_path: P,
+        options: &NodeOptions,
+    ) -> Result<Self> {
+        Ok(WebSocketConfig {
+            headers,
+            connector: tls_connector(cert_path, options)?,
+            proxy: options.proxy.clone(),
+        })
+    }
+
+    /// Open a WebSocket connection with the node, returning a sink of requests and a stream of responses.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host address of the node, using the `http` or `https` scheme.
+    /// * `path` - The path of the endpoint, including the query string.
+    ///
+    pub(crate) async fn connect<Req, Resp>(
+        &self,
+        host: &str,
+        path: &str,
+    ) -> Result<(NodeSink<Req>, NodeStream<Resp>)>
+    where
+        Req: Serialize + Send + 'static,
+        Resp: DeserializeOwned + Send + 'static,
+    {
+        let url = websocket_url(host, path)?;
+        // IPv6 addresses are bracketed in URLs, but must be connected to without brackets.
+        let target_host = match url.host() {
+            Some(Host::Domain(domain)) => domain.to_string(),
+            Some(Host::Ipv4(addr)) => addr.to_string(),
+            Some(Host::Ipv6(addr)) => addr.to_string(),
+            None => return Err(NodeError::InvalidUrl(host.to_string())),
+        };
+        let target_port = url
+            .port_or_known_default()
+            .ok_or_else(|| NodeError::InvalidUrl(host.to_string()))?;
+
+        let mut request = url.as_str().into_client_request()?;
+        request.headers_mut().extend(self.headers.clone());
+
+        let stream = match &self.proxy {
+            // The target is sent as a host name, so that the proxy resolves it, as with `socks5h`.
+            Some(proxy) => {
+                Socks5Stream::connect(proxy.as_str(), (target_host.as_str(), target_port))
+                    .await?
+                    .into_inner()
+            }
+            None => TcpStream::connect((target_host.as_str(), target_port)).await?,
+        };
+
+        let (socket, _) = tokio_tungstenite::client_async_tls_with_config(
+            request,
+            stream,
+            None,
+            self.connector.clone(),
+        )
+        .await?;
+
+        let (write, read) = socket.split();
+
+        let sink = write
+            .sink_map_err(NodeError::from)
+            .with(|request: Req| async move {
+                Ok::<_, NodeError>(Message::Text(serde_json::to_string(&request)?))
+            });
+
+        let stream = read.filter_map(|message| async move {
+            match message {
+                Ok(Message::Text(text)) => Some(parse_frame(text.as_bytes())),
+                Ok(Message::Binary(data)) => Some(parse_frame(&data)),
+                // Pings are answered by the WebSocket library, and a close message ends the stream.
+                Ok(_) => None,
+                Err(err) => Some(Err(err.into())),
+            }
+        });
+
+        Ok((Box::pin(sink), Box::pin(stream)))
+    }
+}
+
+/// Build the WebSocket URL of an endpoint, mapping the `https` scheme to `wss` and the `http` scheme to `ws`.
+///
+/// # Arguments
+///
+/// * `host` - The host address of the node, using the `http` or `https` scheme.
+/// * `path` - The path of the endpoint, including the query string.
+///
+fn websocket_url(host: &str, path: &str) -> Result<reqwest::Url> {
+    let mut url = reqwest::Url::parse(&format!("{host}{path}"))
+        .map_err(|_| NodeError::InvalidUrl(host.to_string()))?;
+
+    let scheme = match url.scheme() {
+        "https" => "wss",
+        "http" => "ws",
+        _ => return Err(NodeError::InvalidUrl(host.to_string())),
+    };
+    url.set_scheme(scheme)
+        .map_err(|_| NodeError::InvalidUrl(host.to_string()))?;
+
+    Ok(url)
+}
+
+/// Build the TLS connector trusting the node certificate, using the same TLS library as the HTTP client.
+///
+/// # Arguments
+///
+/// * `cert_path` - The path to the certificate file.
+/// * `options` - A reference to a [NodeOptions] object containing the optional settings.
+///
+fn tls_connector<P: AsRef<Path>>(cert_path: P, options: &NodeOptions) -> Result<Option<Connector>> {
+    match options.pinned_tls_config()? {
+        #[cfg(feature = "rustls")]
+        Some(tls) => Ok(Some(Connector::Rustls(std::sync::Arc::new(tls)))),
+        _ => Ok(Some(root_connector(cert_path.as_ref())?)),
+    }
+}
+
+/// Build the TLS connector trusting the certificate file with native-tls, which the HTTP client uses by default when
+/// both TLS features are enabled.
+///
+/// # Arguments
+///
+/// * `cert_path` - The path to the certificate file.
+///
+#[cfg(feature = "native-tls")]
+fn root_connector(cert_path: &Path) -> Result<Connector> {
+    let cert = native_tls::Certificate::from_pem(&std::fs::read(cert_path)?)
+        .map_err(|err| WebSocketError::Tls(TlsError::Native(err)))?;
+    let connector = native_tls::TlsConnector::builder()
+        .add_root_certificate(cert)
+        .build()
+        .map_err(|err| WebSocketError::Tls(TlsError::Native(err)))?;
+
+    Ok(Connector::NativeTls(connector))
+}
+
+/// Build the TLS connector trusting the certificate file with rustls.
+///
+/// # Arguments
+///
+/// * `cert_path` - The path to the certificate file.
+///
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+fn root_connector(cert_path: &Path) -> Result<Connector> {
+    let pem = std::fs::read_to_string(cert_path)?;
+    let mut roots = rustls::RootCertStore::empty();
+    roots
+        .add(&rustls::Certificate(pem_to_der(&pem)?))
+        .map_err(|err| WebSocketError::Tls(TlsError::Rustls(err)))?;
+    let tls = rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    Ok(Connector::Rustls(std::sync::Arc::new(tls)))
+}
+
+/// Decode the first certificate of a PEM file.
+///
+/// # Arguments
+///
+/// * `pem` - The content of the PEM file.
+///
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+fn pem_to_der(pem: &str) -> Result<Vec<u8>> {
+    use base64::prelude::{Engine, BASE64_STANDARD};
+
+    let body: String = pem
+        .lines()
+        .skip_while(|line| !line.starts_with("-----BEGIN CERTIFICATE-----"))
+        .skip(1)
+        .take_while(|line| !line.starts_with("-----END CERTIFICATE-----"))
+        .collect();
+
+    Ok(BASE64_STANDARD.decode(body)?)
+}
@@ -0,0 +1,114 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::node::{Node, NodeError, Result};
+use crate::types::{BumpFeeRequest, GetTransactionsRequest, OutPoint, Transaction};
+
+/// Represent an on-chain payment sent by the wallet that has not confirmed for a while, e.g. because its fee rate was
+/// too low during a fee spike.
+///
+/// It can be bumped with child-pays-for-parent (CPFP) when one of its outputs belongs to the wallet, usually the
+/// change output: the node spends that output with a fee high enough to have both transactions mined.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StuckWithdrawal {
+    /// The unconfirmed transaction.
+    pub transaction: Transaction,
+    /// The time elapsed since the transaction was published.
+    pub age: Duration,
+}
+
+impl StuckWithdrawal {
+    /// Find the payments sent by the wallet that are still unconfirmed after the given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node to retrieve the transactions from.
+    /// * `min_age` - The time after which an unconfirmed payment is considered stuck.
+    ///
+    pub async fn find(node: &Node, min_age: Duration) -> Result<Vec<Self>> {
+        let block_height = node.get_info().await?.block_height;
+
+        // An end height of -1 includes the unconfirmed transactions.
+        let get_transactions_request = GetTransactionsRequest {
+            start_height: block_height as i32,
+            end_height: -1,
+            ..Default::default()
+        };
+        let transactions = node
+            .get_transactions(&get_transactions_request)
+            .await?
+            .transactions;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        let mut stuck = Vec::new();
+        for transaction in transactions {
+            let amount: i64 = transaction.amount.parse()?;
+            if transaction.num_confirmations > 0 || amount >= 0 {
+                continue;
+            }
+
+            let published = Duration::from_secs(transaction.time_stamp.parse()?);
+            let age = now.saturating_sub(published);
+            if age >= min_age {
+                stuck.push(StuckWithdrawal { transaction, age });
+            }
+        }
+
+        Ok(stuck)
+    }
+
+    /// Return the outputs of the transaction belonging to the wallet, which can be spent to bump its fee, from the
+    /// largest to the smallest.
+    pub fn outpoints(&self) -> Result<Vec<OutPoint>> {
+        let mut outputs = Vec::new();
+        for output in self.transaction.output_details.iter() {
+            if output.is_our_address {
+                let amount: i64 = output.amount.parse()?;
+                let outpoint = OutPoint {
+                    txid_str: self.transaction.tx_hash.clone(),
+                    output_index: output.output_index.parse()?,
+                    ..Default::default()
+                };
+                outputs.push((amount, outpoint));
+            }
+        }
+
+        outputs.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        Ok(outputs.into_iter().map(|(_, outpoint)| outpoint).collect())
+    }
+
+    /// Bump the fee of the transaction with CPFP, by spending its largest output belonging to the wallet.
+    ///
+    /// Return the output spent by the child transaction, or [NodeError::NoWalletOutput] if the transaction has no
+    /// output belonging to the wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node owning the transaction.
+    /// * `target_conf` - The number of blocks within which both transactions should confirm, if no fee rate is set.
+    /// * `sat_per_vbyte` - The fee rate of the child transaction, in satoshis per virtual byte.
+    ///
+    pub async fn bump(
+        &self,
+        node: &Node,
+        target_conf: Option<u32>,
+        sat_per_vbyte: Option<u64>,
+    ) -> Result<OutPoint> {
+        let Some(outpoint) = self.outpoints()?.into_iter().next() else {
+            return Err(NodeError::NoWalletOutput(self.transaction.tx_hash.clone()));
+        };
+
+        let bump_fee_request = BumpFeeRequest {
+            outpoint: outpoint.clone(),
+            target_conf,
+            sat_per_vbyte,
+            ..Default::default()
+        };
+        node.bump_fee(&bump_fee_request).await?;
+
+        Ok(outpoint)
+    }
+}
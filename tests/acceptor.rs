@@ -0,0 +1,107 @@
+#![cfg(feature = "websocket")]
+
+mod common;
+
+use common::{WebSocketServer, CERT_PATH, MACAROON_PATH};
+use lnd_rest::node::Node;
+use lnd_rest::types::{ChannelAcceptResponse, CommitmentType};
+
+fn channel_accept_request(node_pubkey: &str, pending_chan_id: &str, funding_amt: u64) -> String {
+    format!(
+        r#"{{"result": {{
+            "node_pubkey": "{node_pubkey}",
+            "chain_hash": "b8AAAA==",
+            "pending_chan_id": "{pending_chan_id}",
+            "funding_amt": "{funding_amt}",
+            "push_amt": "0",
+            "dust_limit": "354",
+            "max_value_in_flight": "990000000",
+            "channel_reserve": "10000",
+            "min_htlc": "1",
+            "fee_per_kw": "2500",
+            "csv_delay": 144,
+            "max_accepted_htlcs": 483,
+            "channel_flags": 1,
+            "commitment_type": "ANCHORS",
+            "wants_zero_conf": false,
+            "wants_scid_alias": false
+        }}}}"#
+    )
+}
+
+#[tokio::test]
+async fn channel_openings_are_answered_by_the_policy() {
+    let server = WebSocketServer::start(vec![
+        channel_accept_request("AqqqAA==", "AQE=", 2_000_000),
+        channel_accept_request("A7u7AA==", "AgI=", 20_000),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    node.channel_acceptor(|request| async move {
+        assert_eq!(request.commitment_type, CommitmentType::ANCHORS);
+
+        if request.funding_amt.parse::<u64>().unwrap() < 1_000_000 {
+            return ChannelAcceptResponse::reject("channel too small");
+        }
+        ChannelAcceptResponse {
+            min_accept_depth: 6,
+            ..ChannelAcceptResponse::accept()
+        }
+    })
+    .await
+    .unwrap();
+
+    let (path, headers) = server.request.lock().unwrap().clone().unwrap();
+    assert_eq!(path, "/v1/channels/acceptor?method=POST");
+    assert!(headers.contains(&"grpc-metadata-macaroon".to_string()));
+
+    let received: Vec<serde_json::Value> = server
+        .received
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|text| serde_json::from_str(text).unwrap())
+        .collect();
+    assert_eq!(received[0]["accept"], true);
+    assert_eq!(received[0]["pending_chan_id"], "AQE=");
+    assert_eq!(received[0]["min_accept_depth"], 6);
+    assert_eq!(received[1]["accept"], false);
+    assert_eq!(received[1]["pending_chan_id"], "AgI=");
+    assert_eq!(received[1]["error"], "channel too small");
+}
+
+#[tokio::test]
+async fn undecodable_channel_openings_are_rejected() {
+    let server = WebSocketServer::start(vec![
+        channel_accept_request("AqqqAA==", "AQE=", 2_000_000)
+            .replace("\"ANCHORS\"", "\"FUTURE_COMMITMENT_TYPE\""),
+        channel_accept_request("A7u7AA==", "AgI=", 2_000_000),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    node.channel_acceptor(|request| async move {
+        assert_eq!(request.pending_chan_id, "AgI=");
+        ChannelAcceptResponse::accept()
+    })
+    .await
+    .unwrap();
+
+    let received: Vec<serde_json::Value> = server
+        .received
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|text| serde_json::from_str(text).unwrap())
+        .collect();
+    assert_eq!(received[0]["accept"], false);
+    assert_eq!(received[0]["pending_chan_id"], "AQE=");
+    assert_eq!(received[0]["error"], "unsupported channel request");
+    assert_eq!(received[1]["accept"], true);
+    assert_eq!(received[1]["pending_chan_id"], "AgI=");
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use common::{node, route, MockServer};
+use lnd_rest::types::{
+    AccountAddressType, ImportAccountRequest, ImportPublicKeyRequest, KeyLocator, KeyReq,
+    ListAccountsRequest, ListAddressesRequest,
+};
+
+const ACCOUNT: &str = r#"{
+    "name": "cold",
+    "address_type": "TAPROOT_PUBKEY",
+    "extended_public_key": "xpub6",
+    "master_key_fingerprint": "3q2+7w==",
+    "derivation_path": "m/86'/0'/0'",
+    "external_key_count": 0,
+    "internal_key_count": 0,
+    "watch_only": true
+}"#;
+
+const ADDRESSES: &str = r#"{
+    "account_with_addresses": [{
+        "name": "default",
+        "address_type": "WITNESS_PUBKEY_HASH",
+        "derivation_path": "m/84'/0'/0'",
+        "addresses": [
+            {"address": "bc1qexternal", "is_internal": false, "balance": "100000"},
+            {"address": "bc1qchange", "is_internal": true, "balance": "0", "derivation_path": "m/84'/0'/0'/1/0", "public_key": "AqqqAA=="}
+        ]
+    }]
+}"#;
+
+#[tokio::test]
+async fn accounts_are_listed_and_imported() {
+    let server = MockServer::start(vec![
+        route(
+            "GET",
+            "/v2/wallet/accounts",
+            &format!(r#"{{"accounts": [{ACCOUNT}]}}"#),
+        ),
+        route(
+            "POST",
+            "/v2/wallet/accounts/import",
+            &format!(
+                r#"{{"account": {ACCOUNT}, "dry_run_external_addrs": ["bc1pexternal"], "dry_run_internal_addrs": ["bc1pchange"]}}"#
+            ),
+        ),
+        route("POST", "/v2/wallet/key/import", "{}"),
+        route("GET", "/v2/wallet/addresses", ADDRESSES),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let list_accounts_request = ListAccountsRequest {
+        address_type: Some(AccountAddressType::TAPROOT_PUBKEY),
+        ..Default::default()
+    };
+    let accounts = node
+        .list_accounts(&list_accounts_request)
+        .await
+        .unwrap()
+        .accounts;
+    assert!(accounts[0].watch_only);
+    assert_eq!(accounts[0].address_type, AccountAddressType::TAPROOT_PUBKEY);
+
+    let import_account_request = ImportAccountRequest {
+        name: "cold".to_string(),
+        extended_public_key: "xpub6".to_string(),
+        master_key_fingerprint: "3q2+7w==".to_string(),
+        address_type: AccountAddressType::TAPROOT_PUBKEY,
+        dry_run: true,
+    };
+    let imported = node.import_account(&import_account_request).await.unwrap();
+    assert_eq!(imported.account.derivation_path, "m/86'/0'/0'");
+    assert_eq!(imported.dry_run_external_addrs, ["bc1pexternal"]);
+
+    let import_public_key_request = ImportPublicKeyRequest {
+        public_key: "AqqqAA==".to_string(),
+        address_type: AccountAddressType::WITNESS_PUBKEY_HASH,
+    };
+    node.import_public_key(&import_public_key_request)
+        .await
+        .unwrap();
+
+    let list_addresses_request = ListAddressesRequest {
+        account_name: Some("default".to_string()),
+        ..Default::default()
+    };
+    let addresses = node
+        .list_addresses(&list_addresses_request)
+        .await
+        .unwrap()
+        .account_with_addresses;
+    assert_eq!(addresses[0].addresses[0].derivation_path, "");
+    assert_eq!(addresses[0].addresses[1].public_key, "AqqqAA==");
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/v2/wallet/accounts?address_type=TAPROOT_PUBKEY"
+    );
+    let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+    assert_eq!(body["address_type"], "TAPROOT_PUBKEY");
+    assert_eq!(body["dry_run"], true);
+    let body: serde_json::Value = serde_json::from_str(&requests[2].body).unwrap();
+    assert_eq!(body["address_type"], "WITNESS_PUBKEY_HASH");
+    assert_eq!(
+        requests[3].path,
+        "/v2/wallet/addresses?account_name=default&show_custom_accounts=false"
+    );
+}
+
+#[tokio::test]
+async fn keys_are_derived() {
+    let server = MockServer::start(vec![
+        route(
+            "POST",
+            "/v2/wallet/key",
+            r#"{"raw_key_bytes": "AqqqAA==", "key_loc": {"key_family": 6, "key_index": 0}}"#,
+        ),
+        route(
+            "POST",
+            "/v2/wallet/key/next",
+            r#"{"raw_key_bytes": "A7u7AA==", "key_loc": {"key_family": 805, "key_index": 3}}"#,
+        ),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let key_locator = KeyLocator {
+        key_family: 6,
+        key_index: 0,
+    };
+    let key = node.derive_key(&key_locator).await.unwrap();
+    assert_eq!(key.raw_key_bytes, "AqqqAA==");
+    assert_eq!(key.key_loc, key_locator);
+
+    let key_req = KeyReq {
+        key_family: 805,
+        ..Default::default()
+    };
+    let key = node.derive_next_key(&key_req).await.unwrap();
+    assert_eq!(key.key_loc.key_index, 3);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].body, r#"{"key_family":6,"key_index":0}"#);
+    assert_eq!(
+        requests[1].body,
+        r#"{"key_finger_print":0,"key_family":805}"#
+    );
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use std::fs;
+
+use common::{node, route, MockServer};
+use lnd_rest::backup::BackupWriter;
+use lnd_rest::types::{ChanBackupSnapshot, ChannelBackups, RestoreChanBackupRequest};
+
+const CHANNEL_BACKUP: &str = r#"{
+    "chan_point": {"funding_txid_bytes": "qqo=", "output_index": 1},
+    "chan_backup": "c2luZ2xl"
+}"#;
+
+fn snapshot(multi_chan_backup: &str) -> String {
+    format!(
+        r#"{{
+            "single_chan_backups": {{"chan_backups": [{CHANNEL_BACKUP}]}},
+            "multi_chan_backup": {{
+                "chan_points": [{{"funding_txid_bytes": "qqo=", "output_index": 1}}],
+                "multi_chan_backup": "{multi_chan_backup}"
+            }}
+        }}"#
+    )
+}
+
+#[tokio::test]
+async fn channel_backups_are_exported_verified_and_restored() {
+    let server = MockServer::start(vec![
+        route("GET", "/v1/channels/backup/aaaa/1", CHANNEL_BACKUP),
+        route("GET", "/v1/channels/backup", &snapshot("bXVsdGk=")),
+        route("POST", "/v1/channels/backup/verify", "{}"),
+        route("POST", "/v1/channels/backup/restore", "{}"),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let backup = node.export_channel_backup("aaaa", 1).await.unwrap();
+    assert_eq!(backup.chan_point.txid().unwrap(), "aaaa");
+    assert_eq!(backup.chan_backup, "c2luZ2xl");
+
+    let snapshot = node.export_all_channel_backups().await.unwrap();
+    let multi_chan_backup = snapshot.multi_chan_backup.unwrap();
+    assert_eq!(multi_chan_backup.chan_points.len(), 1);
+
+    let chan_backup_snapshot = ChanBackupSnapshot {
+        multi_chan_backup: Some(multi_chan_backup.clone()),
+        ..Default::default()
+    };
+    node.verify_chan_backup(&chan_backup_snapshot)
+        .await
+        .unwrap();
+
+    let restore_chan_backup_request = RestoreChanBackupRequest {
+        chan_backups: Some(ChannelBackups {
+            chan_backups: vec![backup],
+        }),
+        ..Default::default()
+    };
+    node.restore_channel_backups(&restore_chan_backup_request)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    let body: serde_json::Value = serde_json::from_str(&requests[2].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "multi_chan_backup": {
+                "chan_points": [{"funding_txid_bytes": "qqo=", "output_index": 1}],
+                "multi_chan_backup": "bXVsdGk="
+            }
+        })
+    );
+    let body: serde_json::Value = serde_json::from_str(&requests[3].body).unwrap();
+    assert_eq!(
+        body["chan_backups"]["chan_backups"][0]["chan_backup"],
+        "c2luZ2xl"
+    );
+    assert!(body.get("multi_chan_backup").is_none());
+}
+
+#[tokio::test]
+async fn new_backups_are_written_with_rotation() {
+    // "Zmlyc3Q=" is "first", "c2Vjb25k" is "second" and "dGhpcmQ=" is "third".
+    let updates = [snapshot("c2Vjb25k"), snapshot("dGhpcmQ=")]
+        .iter()
+        .map(|snapshot| {
+            let snapshot: serde_json::Value = serde_json::from_str(snapshot).unwrap();
+            serde_json::json!({ "result": snapshot }).to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let server = MockServer::start(vec![
+        route("GET", "/v1/channels/backup/subscribe", &updates),
+        route("GET", "/v1/channels/backup", &snapshot("Zmlyc3Q=")),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let dir = std::env::temp_dir().join(format!("lnd_rest_backups_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("notes.txt"), "not a backup").unwrap();
+
+    let writer = BackupWriter::new(&dir, 2);
+    writer.run(&node).await.unwrap();
+
+    let backups = writer.backups().unwrap();
+    assert_eq!(backups.len(), 2);
+    assert_eq!(fs::read(&backups[0]).unwrap(), b"second");
+    assert_eq!(fs::read(&backups[1]).unwrap(), b"third");
+
+    // Only the backups and the unrelated file are left, without temporary files.
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 3);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
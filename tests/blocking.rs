@@ -0,0 +1,107 @@
+#![cfg(feature = "blocking")]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+use lnd_rest::blocking::BlockingNode;
+use lnd_rest::types::{InvoiceState, WalletState};
+
+const INVOICE: &str = r#"{
+    "memo": "coffee",
+    "r_preimage": "",
+    "r_hash": "",
+    "value": "1",
+    "value_msat": "1000",
+    "settled": false,
+    "settle_date": "0",
+    "creation_date": "1690000000",
+    "payment_request": "lnbc10n1",
+    "expiry": "3600",
+    "state": "OPEN"
+}"#;
+
+#[test]
+fn lookup_invoice_blocks_until_response() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut data = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !data.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            data.extend_from_slice(&buf[..n]);
+        }
+
+        let http_response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            INVOICE.len(),
+            INVOICE
+        );
+        stream.write_all(http_response.as_bytes()).unwrap();
+
+        String::from_utf8(data).unwrap()
+    });
+
+    let node = BlockingNode::init(
+        format!("http://127.0.0.1:{port}"),
+        "tests/data/admin.macaroon",
+        "tests/data/tls.cert",
+    )
+    .unwrap();
+
+    let invoice = node.lookup_invoice(&"abcd".to_string()).unwrap();
+    assert_eq!(invoice.memo, "coffee");
+    assert_eq!(invoice.state, InvoiceState::OPEN);
+
+    let http_request = server.join().unwrap();
+    assert!(http_request.starts_with("GET /v1/invoice/abcd HTTP/1.1\r\n"));
+}
+
+#[test]
+fn subscriptions_outlive_the_default_client_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut data = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !data.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            data.extend_from_slice(&buf[..n]);
+        }
+
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ntransfer-encoding: chunked\r\n\r\n")
+            .unwrap();
+
+        // Stay idle for longer than the 30 seconds default timeout of the blocking client
+        thread::sleep(Duration::from_secs(31));
+
+        let frame = "{\"result\": {\"state\": \"SERVER_ACTIVE\"}}\n";
+        let chunk = format!("{:x}\r\n{frame}\r\n0\r\n\r\n", frame.len());
+        stream.write_all(chunk.as_bytes()).unwrap();
+    });
+
+    let node = BlockingNode::init(
+        format!("http://127.0.0.1:{port}"),
+        "tests/data/admin.macaroon",
+        "tests/data/tls.cert",
+    )
+    .unwrap();
+
+    let states: Vec<_> = node
+        .subscribe_state()
+        .unwrap()
+        .map(|state| state.unwrap().state)
+        .collect();
+    assert_eq!(states, [WalletState::SERVER_ACTIVE]);
+
+    server.join().unwrap();
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::{route, MockServer, CERT_PATH, MACAROON_PATH};
+use futures_util::StreamExt;
+use lnd_rest::node::Node;
+use lnd_rest::types::{ChannelEventUpdate, ClosureType, CommitmentType, Initiator};
+
+const CHANNEL_EVENTS: &str = r#"{"result": {"type": "PENDING_OPEN_CHANNEL", "pending_open_channel": {"txid": "qqo=", "output_index": 0}}}
+{"result": {"type": "OPEN_CHANNEL", "open_channel": {"active": true, "remote_pubkey": "03aaaa", "channel_point": "aaaa:0", "chan_id": "880000000000000000", "capacity": "1000000", "local_balance": "996530", "remote_balance": "0", "commit_fee": "2810", "commit_weight": "1116", "fee_per_kw": "2500", "unsettled_balance": "0", "total_satoshis_sent": "0", "total_satoshis_received": "0", "num_updates": "0", "pending_htlcs": [], "csv_delay": 144, "private": false, "initiator": true, "chan_status_flags": "ChanStatusDefault", "local_chan_reserve_sat": "10000", "remote_chan_reserve_sat": "10000", "static_remote_key": false, "commitment_type": "ANCHORS", "lifetime": "0", "uptime": "0", "close_address": "", "push_amount_sat": "0", "thaw_height": 0}}}
+{"result": {"type": "ACTIVE_CHANNEL", "active_channel": {"funding_txid_bytes": "qqo=", "output_index": 0}}}
+{"result": {"type": "INACTIVE_CHANNEL", "inactive_channel": {"funding_txid_bytes": "qqo=", "output_index": 0}}}
+{"result": {"type": "CLOSED_CHANNEL", "closed_channel": {"channel_point": "aaaa:0", "chan_id": "880000000000000000", "chain_hash": "", "closing_tx_hash": "bbbb", "remote_pubkey": "03aaaa", "capacity": "1000000", "close_height": 800100, "settled_balance": "996530", "time_locked_balance": "0", "close_type": "REMOTE_FORCE_CLOSE", "open_initiator": "INITIATOR_LOCAL", "close_initiator": "INITIATOR_REMOTE", "resolutions": []}}}
+{"result": {"type": "FULLY_RESOLVED_CHANNEL", "fully_resolved_channel": {"funding_txid_bytes": "qqo=", "output_index": 0}}}
+"#;
+
+#[tokio::test]
+async fn channel_events_are_typed() {
+    let server =
+        MockServer::start(vec![route("GET", "/v1/channels/subscribe", CHANNEL_EVENTS)]).await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let events: Vec<ChannelEventUpdate> = node
+        .subscribe_channel_events()
+        .await
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 6);
+    assert!(matches!(
+        events[0],
+        ChannelEventUpdate::PendingOpenChannel(_)
+    ));
+    match &events[1] {
+        ChannelEventUpdate::OpenChannel(channel) => {
+            assert_eq!(channel.commitment_type, CommitmentType::ANCHORS);
+        }
+        event => panic!("unexpected event: {event:?}"),
+    }
+    assert!(matches!(events[2], ChannelEventUpdate::ActiveChannel(_)));
+    match &events[3] {
+        ChannelEventUpdate::InactiveChannel(point) => {
+            assert_eq!(point.funding_txid_bytes.as_deref(), Some("qqo="));
+        }
+        event => panic!("unexpected event: {event:?}"),
+    }
+    match &events[4] {
+        ChannelEventUpdate::ClosedChannel(summary) => {
+            assert_eq!(summary.close_type, ClosureType::REMOTE_FORCE_CLOSE);
+            assert_eq!(summary.close_initiator, Initiator::INITIATOR_REMOTE);
+        }
+        event => panic!("unexpected event: {event:?}"),
+    }
+    assert!(matches!(
+        events[5],
+        ChannelEventUpdate::FullyResolvedChannel(_)
+    ));
+}
@@ -0,0 +1,265 @@
+#![allow(dead_code)]
+
+use std::sync::{Arc, Mutex};
+
+use lnd_rest::node::Node;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+#[cfg(feature = "websocket")]
+use {
+    futures_util::{SinkExt, StreamExt},
+    tokio_tungstenite::tungstenite::handshake::server::{Request as UpgradeRequest, Response},
+    tokio_tungstenite::tungstenite::Message,
+};
+
+pub const MACAROON_PATH: &str = "tests/data/admin.macaroon";
+pub const CERT_PATH: &str = "tests/data/tls.cert";
+
+/// A canned response served for a method and path.
+#[derive(Clone, Debug)]
+pub struct Route {
+    pub method: &'static str,
+    pub path: String,
+    pub status: u16,
+    pub body: String,
+}
+
+/// Answer requests to `path` with a 200 status and the given JSON body.
+pub fn route(method: &'static str, path: &str, body: &str) -> Route {
+    Route {
+        method,
+        path: path.to_string(),
+        status: 200,
+        body: body.to_string(),
+    }
+}
+
+/// A request received by a [MockServer].
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    /// The path, including the query string.
+    pub path: String,
+    /// The headers, with lowercase names.
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+/// A plain HTTP server standing in for the LND REST API.
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    /// Start a server answering each request with the route matching its method and path, ignoring the query string.
+    ///
+    /// When several routes match, they are served in order, and the last one is served again once all were used.
+    pub async fn start(routes: Vec<Route>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let served = Arc::new(Mutex::new(vec![false; routes.len()]));
+
+        let recorded = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let routes = routes.clone();
+                let recorded = recorded.clone();
+                let served = served.clone();
+                tokio::spawn(async move { serve(stream, routes, served, recorded).await });
+            }
+        });
+
+        MockServer { url, requests }
+    }
+
+    /// Return the requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn serve(
+    mut stream: TcpStream,
+    routes: Vec<Route>,
+    served: Arc<Mutex<Vec<bool>>>,
+    recorded: Arc<Mutex<Vec<Request>>>,
+) {
+    let mut data = Vec::new();
+    let mut buf = [0u8; 4096];
+
+    let header_end = loop {
+        if let Some(pos) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        let n = stream.read(&mut buf).await.unwrap();
+        if n == 0 {
+            return;
+        }
+        data.extend_from_slice(&buf[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&data[..header_end]).to_string();
+    let content_length = head
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .map(|(_, value)| value.trim().parse::<usize>().unwrap())
+        .unwrap_or(0);
+
+    while data.len() < header_end + content_length {
+        let n = stream.read(&mut buf).await.unwrap();
+        data.extend_from_slice(&buf[..n]);
+    }
+
+    let mut request_line = head.lines().next().unwrap().split(' ');
+    let method = request_line.next().unwrap().to_string();
+    let path = request_line.next().unwrap().to_string();
+    let body = String::from_utf8_lossy(&data[header_end..header_end + content_length]).to_string();
+
+    let headers = head
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    recorded.lock().unwrap().push(Request {
+        method: method.clone(),
+        path: path.clone(),
+        headers,
+        body,
+    });
+
+    let bare_path = path.split('?').next().unwrap();
+    let matching: Vec<usize> = (0..routes.len())
+        .filter(|&i| routes[i].method == method && routes[i].path == bare_path)
+        .collect();
+    let index = {
+        let mut served = served.lock().unwrap();
+        let index = matching
+            .iter()
+            .find(|&&i| !served[i])
+            .or(matching.last())
+            .copied();
+        if let Some(i) = index {
+            served[i] = true;
+        }
+        index
+    };
+    let (status, body) = index
+        .map(|i| (routes[i].status, routes[i].body.clone()))
+        .unwrap_or((
+            404,
+            r#"{"code": 5, "message": "Not Found", "details": []}"#.to_string(),
+        ));
+
+    let response = format!(
+        "HTTP/1.1 {status} Status\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await.unwrap();
+}
+
+/// The path of a WebSocket upgrade request, including the query string, and its header names.
+#[cfg(feature = "websocket")]
+pub type Upgrade = (String, Vec<String>);
+
+/// A WebSocket server standing in for the node, recording the upgrade request and the messages received.
+#[cfg(feature = "websocket")]
+pub struct WebSocketServer {
+    pub url: String,
+    pub request: Arc<Mutex<Option<Upgrade>>>,
+    pub received: Arc<Mutex<Vec<String>>>,
+}
+
+#[cfg(feature = "websocket")]
+impl WebSocketServer {
+    /// Start a server sending the given messages once connected, then closing the connection once the client replied
+    /// to each of them.
+    pub async fn start(messages: Vec<String>) -> Self {
+        Self::start_on("127.0.0.1:0", messages).await
+    }
+
+    /// Start a server listening on the given address, e.g. `[::1]:0` to test IPv6 hosts.
+    pub async fn start_on(addr: &str, messages: Vec<String>) -> Self {
+        let listener = TcpListener::bind(addr).await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let request = Arc::new(Mutex::new(None));
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let (recorded, stored) = (request.clone(), received.clone());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            #[allow(clippy::result_large_err)]
+            let callback = |request: &UpgradeRequest, response: Response| {
+                let headers = request
+                    .headers()
+                    .keys()
+                    .map(|name| name.to_string())
+                    .collect();
+                *recorded.lock().unwrap() = Some((request.uri().to_string(), headers));
+                Ok(response)
+            };
+            let mut socket = tokio_tungstenite::accept_hdr_async(stream, callback)
+                .await
+                .unwrap();
+
+            let replies = messages.len();
+            for message in messages {
+                socket.send(Message::Text(message)).await.unwrap();
+            }
+            while stored.lock().unwrap().len() < replies {
+                match socket.next().await {
+                    Some(Ok(Message::Text(text))) => stored.lock().unwrap().push(text),
+                    Some(Ok(_)) => continue,
+                    _ => return,
+                }
+            }
+            socket.close(None).await.unwrap();
+            // Reading on completes the closing handshake.
+            while socket.next().await.is_some() {}
+        });
+
+        WebSocketServer {
+            url,
+            request,
+            received,
+        }
+    }
+}
+
+/// Return a [Node] talking to the mock server.
+pub async fn node(server: &MockServer) -> Node {
+    Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap()
+}
+
+/// Return the body of a `getinfo` response.
+pub fn get_info(alias: &str, synced_to_chain: bool) -> String {
+    format!(
+        r##"{{
+            "version": "0.17.0-beta",
+            "commit_hash": "",
+            "identity_pubkey": "02aaaa",
+            "alias": "{alias}",
+            "color": "#3399ff",
+            "num_pending_channels": 0,
+            "num_active_channels": 1,
+            "num_inactive_channels": 0,
+            "num_peers": 1,
+            "block_height": 800000,
+            "block_hash": "0000",
+            "best_header_timestamp": "1690000000",
+            "synced_to_chain": {synced_to_chain},
+            "synced_to_graph": true,
+            "testnet": false,
+            "chains": [{{"chain": "bitcoin", "network": "mainnet"}}],
+            "uris": [],
+            "features": {{"9": {{"name": "tlv-onion", "is_required": false, "is_known": true}}}}
+        }}"##
+    )
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::{route, MockServer, CERT_PATH, MACAROON_PATH};
+use lnd_rest::node::Node;
+use lnd_rest::types::{ChannelPoint, InboundFee, PolicyUpdateRequest, UpdateFailure};
+
+const FEE_REPORT: &str = r#"{
+    "channel_fees": [{
+        "chan_id": "880000000000000000",
+        "channel_point": "bbaa:0",
+        "base_fee_msat": "1000",
+        "fee_per_mil": "500",
+        "fee_rate": 0.0005,
+        "inbound_base_fee_msat": -100,
+        "inbound_fee_per_mil": -50
+    }, {
+        "chan_id": "890000000000000000",
+        "channel_point": "bbaa:1",
+        "base_fee_msat": "0",
+        "fee_per_mil": "10",
+        "fee_rate": 0.00001
+    }],
+    "day_fee_sum": "12",
+    "week_fee_sum": "80",
+    "month_fee_sum": "320"
+}"#;
+
+const FAILED_UPDATES: &str = r#"{
+    "failed_updates": [{
+        "outpoint": {"txid_bytes": "qrs=", "txid_str": "bbaa", "output_index": 1},
+        "reason": "UPDATE_FAILURE_PENDING",
+        "update_error": "channel is pending"
+    }]
+}"#;
+
+#[tokio::test]
+async fn fees_are_reported_and_updated() {
+    let server = MockServer::start(vec![
+        route("GET", "/v1/fees", FEE_REPORT),
+        route("POST", "/v1/chanpolicy", FAILED_UPDATES),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let report = node.fee_report().await.unwrap();
+    assert_eq!(report.channel_fees[0].inbound_fee_per_mil, -50);
+    assert_eq!(report.channel_fees[1].inbound_fee_per_mil, 0);
+    assert_eq!(report.month_fee_sum, "320");
+
+    let policy_update_request = PolicyUpdateRequest {
+        global: true,
+        base_fee_msat: 1000,
+        fee_rate_ppm: 250,
+        time_lock_delta: 80,
+        ..Default::default()
+    };
+    let response = node
+        .update_channel_policy(&policy_update_request)
+        .await
+        .unwrap();
+    assert_eq!(
+        response.failed_updates[0].reason,
+        UpdateFailure::UPDATE_FAILURE_PENDING
+    );
+
+    let policy_update_request = PolicyUpdateRequest {
+        chan_point: Some(ChannelPoint {
+            funding_txid_str: Some("bbaa".to_string()),
+            output_index: 0,
+            ..Default::default()
+        }),
+        fee_rate_ppm: 100,
+        time_lock_delta: 80,
+        min_htlc_msat: 1000,
+        min_htlc_msat_specified: true,
+        inbound_fee: Some(InboundFee {
+            base_fee_msat: 0,
+            fee_rate_ppm: -25,
+        }),
+        ..Default::default()
+    };
+    node.update_channel_policy(&policy_update_request)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+    assert_eq!(body["global"], true);
+    assert!(body.get("chan_point").is_none());
+    assert!(body.get("inbound_fee").is_none());
+    let body: serde_json::Value = serde_json::from_str(&requests[2].body).unwrap();
+    assert_eq!(
+        body["chan_point"],
+        serde_json::json!({"funding_txid_str": "bbaa", "output_index": 0})
+    );
+    assert!(body.get("global").is_none());
+    assert_eq!(body["min_htlc_msat_specified"], true);
+    assert_eq!(body["inbound_fee"]["fee_rate_ppm"], -25);
+}
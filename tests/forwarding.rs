@@ -0,0 +1,148 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::{route, MockServer, CERT_PATH, MACAROON_PATH};
+use futures_util::TryStreamExt;
+use lnd_rest::forwarding::{ForwardingReport, ForwardingStats};
+use lnd_rest::node::Node;
+use lnd_rest::types::ForwardingHistoryRequest;
+
+fn event(chan_id_in: &str, chan_id_out: &str, amt_out_msat: u64, fee_msat: u64) -> String {
+    format!(
+        r#"{{
+            "chan_id_in": "{chan_id_in}",
+            "chan_id_out": "{chan_id_out}",
+            "amt_in": "{amt_in}",
+            "amt_out": "{amt_out}",
+            "fee": "{fee}",
+            "fee_msat": "{fee_msat}",
+            "amt_in_msat": "{amt_in_msat}",
+            "amt_out_msat": "{amt_out_msat}",
+            "timestamp_ns": "1690000000000000000",
+            "peer_alias_in": "alias-{chan_id_in}",
+            "peer_alias_out": ""
+        }}"#,
+        amt_in = (amt_out_msat + fee_msat) / 1000,
+        amt_out = amt_out_msat / 1000,
+        fee = fee_msat / 1000,
+        amt_in_msat = amt_out_msat + fee_msat,
+    )
+}
+
+#[tokio::test]
+async fn forwarding_history_is_paginated_and_aggregated() {
+    let first_page = format!(
+        r#"{{"forwarding_events": [{}, {}], "last_offset_index": 2}}"#,
+        event("1", "2", 100000, 1000),
+        event("1", "3", 200000, 2000)
+    );
+    let second_page = format!(
+        r#"{{"forwarding_events": [{}], "last_offset_index": 3}}"#,
+        event("3", "2", 50000, 500)
+    );
+    let last_page = r#"{"forwarding_events": [], "last_offset_index": 3}"#;
+    let server = MockServer::start(vec![
+        route("POST", "/v1/switch", &first_page),
+        route("POST", "/v1/switch", &second_page),
+        route("POST", "/v1/switch", last_page),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let forwarding_history_request = ForwardingHistoryRequest {
+        start_time: 1680000000,
+        end_time: 1700000000,
+        num_max_events: 2,
+        ..Default::default()
+    };
+    let events: Vec<_> = node
+        .forwarding_events(&forwarding_history_request)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 3);
+
+    let channel_peers = HashMap::from([
+        ("1".to_string(), "02aaaa".to_string()),
+        ("2".to_string(), "02bbbb".to_string()),
+        ("3".to_string(), "02bbbb".to_string()),
+    ]);
+    let report = ForwardingReport::new(&events, &channel_peers).unwrap();
+    assert_eq!(report.total.fee_msat, 3500);
+    assert_eq!(
+        report.channels["2"],
+        ForwardingStats {
+            forwards_out: 2,
+            volume_out_msat: 150000,
+            fee_msat: 1500,
+            ..Default::default()
+        }
+    );
+    assert_eq!(report.channels["3"].forwards_in, 1);
+    assert_eq!(report.channels["3"].forwards_out, 1);
+    assert_eq!(report.peers["02aaaa"].volume_in_msat, 303000);
+    assert_eq!(report.peers["02bbbb"].forwards_in, 1);
+    assert_eq!(report.peers["02bbbb"].forwards_out, 3);
+    assert_eq!(report.peers.len(), 2);
+    assert_eq!(report.aliases["02aaaa"], "alias-1");
+    assert_eq!(report.aliases["02bbbb"], "alias-3");
+
+    let offsets: Vec<u64> = server
+        .requests()
+        .iter()
+        .map(|request| {
+            let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+            body["index_offset"].as_u64().unwrap()
+        })
+        .collect();
+    assert_eq!(offsets, [0, 2, 3]);
+}
+
+#[tokio::test]
+async fn report_peers_are_resolved_from_open_and_closed_channels() {
+    let events = format!(
+        r#"{{"forwarding_events": [{}, {}], "last_offset_index": 2}}"#,
+        event("1", "2", 100000, 1000),
+        event("2", "4", 50000, 500)
+    );
+    let last_page = r#"{"forwarding_events": [], "last_offset_index": 2}"#;
+    let open_channel = r#"{"active": true, "remote_pubkey": "02aaaa", "channel_point": "aaaa:0", "chan_id": "1", "capacity": "1000000", "local_balance": "500000", "remote_balance": "496530", "commit_fee": "2810", "commit_weight": "1116", "fee_per_kw": "2500", "unsettled_balance": "0", "total_satoshis_sent": "0", "total_satoshis_received": "0", "num_updates": "12", "pending_htlcs": [], "csv_delay": 144, "private": false, "initiator": true, "chan_status_flags": "ChanStatusDefault", "local_chan_reserve_sat": "10000", "remote_chan_reserve_sat": "10000", "static_remote_key": false, "commitment_type": "ANCHORS", "lifetime": "3600", "uptime": "3600", "close_address": "", "push_amount_sat": "0", "thaw_height": 0}"#;
+    let closed_channel = r#"{"channel_point": "bbbb:0", "chan_id": "2", "chain_hash": "", "closing_tx_hash": "cccc", "remote_pubkey": "02bbbb", "capacity": "1000000", "close_height": 800100, "settled_balance": "996530", "time_locked_balance": "0", "close_type": "COOPERATIVE_CLOSE", "open_initiator": "INITIATOR_LOCAL", "close_initiator": "INITIATOR_REMOTE", "resolutions": []}"#;
+    let server = MockServer::start(vec![
+        route("POST", "/v1/switch", &events),
+        route("POST", "/v1/switch", last_page),
+        route(
+            "GET",
+            "/v1/channels",
+            &format!(r#"{{"channels": [{open_channel}]}}"#),
+        ),
+        route(
+            "GET",
+            "/v1/channels/closed",
+            &format!(r#"{{"channels": [{closed_channel}]}}"#),
+        ),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let report = ForwardingReport::load(&node, 1680000000, 1700000000)
+        .await
+        .unwrap();
+
+    assert_eq!(report.peers["02aaaa"].forwards_in, 1);
+    assert_eq!(report.peers["02bbbb"].forwards_in, 1);
+    assert_eq!(report.peers["02bbbb"].forwards_out, 1);
+    assert_eq!(report.peers.len(), 2);
+    assert_eq!(report.channels["4"].forwards_out, 1);
+    assert_eq!(report.aliases["02aaaa"], "alias-1");
+
+    let requests = server.requests();
+    assert!(requests
+        .iter()
+        .any(|request| request.path.starts_with("/v1/channels?active_only=false")));
+}
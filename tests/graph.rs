@@ -0,0 +1,140 @@
+mod common;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use common::{route, MockServer, CERT_PATH, MACAROON_PATH};
+use futures_util::StreamExt;
+use lnd_rest::graph::GraphCache;
+use lnd_rest::node::Node;
+
+const GRAPH: &str = include_str!("data/graph.json");
+
+const NETWORK_INFO: &str = r#"{
+    "graph_diameter": 8,
+    "avg_out_degree": 4.5,
+    "max_out_degree": 2000,
+    "num_nodes": 15000,
+    "num_channels": 60000,
+    "total_network_capacity": "500000000000",
+    "avg_channel_size": 8300000.5,
+    "min_channel_size": "20000",
+    "max_channel_size": "1000000000",
+    "median_channel_size_sat": "2000000",
+    "num_zombie_chans": "100"
+}"#;
+
+const NODE_METRICS: &str = r#"{
+    "betweenness_centrality": {
+        "02aaaa": {"value": 0.25, "normalized_value": 1.0}
+    }
+}"#;
+
+const GRAPH_UPDATES: &str = r##"{"result": {"node_updates": [{"addresses": [], "identity_key": "03bbbb", "global_features": "", "alias": "bobby", "color": "#000000", "node_addresses": [], "features": {}}], "channel_updates": [], "closed_chans": []}}
+{"result": {"node_updates": [], "channel_updates": [{"chan_id": "880000000000000000", "chan_point": {"funding_txid_bytes": "qrs=", "output_index": 0}, "capacity": "1000000", "routing_policy": {"time_lock_delta": 40, "min_htlc": "1000", "fee_base_msat": "0", "fee_rate_milli_msat": "500", "disabled": false, "max_htlc_msat": "990000000", "last_update": 1690000100, "custom_records": {}}, "advertising_node": "03bbbb", "connecting_node": "02aaaa"}], "closed_chans": []}}
+{"result": {"node_updates": [], "channel_updates": [{"chan_id": "890000000000000000", "chan_point": {"funding_txid_bytes": "qrs=", "output_index": 1}, "capacity": "2000000", "routing_policy": {"time_lock_delta": 40, "min_htlc": "1000", "fee_base_msat": "0", "fee_rate_milli_msat": "10", "disabled": false, "max_htlc_msat": "1980000000", "last_update": 1690000200, "custom_records": {}}, "advertising_node": "03bbbb", "connecting_node": "02cccc"}], "closed_chans": []}}
+{"result": {"node_updates": [], "channel_updates": [], "closed_chans": [{"chan_id": "880000000000000000", "capacity": "1000000", "closed_height": 800100, "chan_point": {"funding_txid_bytes": "qrs=", "output_index": 0}}]}}
+"##;
+
+#[tokio::test]
+async fn graph_is_queried() {
+    let graph: serde_json::Value = serde_json::from_str(GRAPH).unwrap();
+    let node_info = serde_json::json!({
+        "node": graph["nodes"][0],
+        "num_channels": 1,
+        "total_capacity": "1000000",
+        "channels": graph["edges"],
+    })
+    .to_string();
+    let edge = graph["edges"][0].to_string();
+
+    let server = MockServer::start(vec![
+        route("GET", "/v1/graph", GRAPH),
+        route("GET", "/v1/graph/node/02aaaa", &node_info),
+        route("GET", "/v1/graph/edge/880000000000000000", &edge),
+        route("GET", "/v1/graph/info", NETWORK_INFO),
+        route("GET", "/v1/graph/nodemetrics", NODE_METRICS),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let graph = node.describe_graph(true).await.unwrap();
+    assert_eq!(graph.nodes.len(), 2);
+    let policy = graph.edges[0].node1_policy.as_ref().unwrap();
+    assert_eq!(policy.fee_rate_milli_msat, "100");
+    assert_eq!(policy.inbound_fee_rate_milli_msat, -50);
+    assert!(graph.edges[0].node2_policy.is_none());
+
+    let info = node
+        .get_node_info(&"02aaaa".to_string(), true)
+        .await
+        .unwrap();
+    assert_eq!(info.node.alias, "alice");
+    assert_eq!(info.channels.len(), 1);
+
+    let edge = node
+        .get_chan_info(&"880000000000000000".to_string())
+        .await
+        .unwrap();
+    assert_eq!(edge.node2_pub, "03bbbb");
+
+    let network = node.get_network_info().await.unwrap();
+    assert_eq!(network.num_nodes, 15000);
+
+    let metrics = node.get_node_metrics().await.unwrap();
+    assert_eq!(
+        metrics.betweenness_centrality["02aaaa"].normalized_value,
+        1.0
+    );
+
+    let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+    assert_eq!(paths[0], "/v1/graph?include_unannounced=true");
+    assert_eq!(paths[1], "/v1/graph/node/02aaaa?include_channels=true");
+    assert_eq!(
+        paths[4],
+        "/v1/graph/nodemetrics?types=BETWEENNESS_CENTRALITY"
+    );
+}
+
+#[tokio::test]
+async fn graph_cache_applies_updates() {
+    let server = MockServer::start(vec![
+        route("GET", "/v1/graph", GRAPH),
+        route("GET", "/v1/graph/subscribe", GRAPH_UPDATES),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let mut updates = node.subscribe_channel_graph().await.unwrap();
+    let mut cache = GraphCache::load(&node, false).await.unwrap();
+    assert_eq!(cache.policy("880000000000000000", "03bbbb"), None);
+
+    let applied_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as u32;
+    cache.apply(&updates.next().await.unwrap().unwrap());
+    let bob = cache.node("03bbbb").unwrap();
+    assert_eq!(bob.alias, "bobby");
+    assert!(bob.last_update >= applied_at);
+
+    cache.apply(&updates.next().await.unwrap().unwrap());
+    let policy = cache.policy("880000000000000000", "03bbbb").unwrap();
+    assert_eq!(policy.fee_rate_milli_msat, "500");
+    let policy = cache.policy("880000000000000000", "02aaaa").unwrap();
+    assert_eq!(policy.fee_rate_milli_msat, "100");
+
+    cache.apply(&updates.next().await.unwrap().unwrap());
+    let edge = cache.edge("890000000000000000").unwrap();
+    assert_eq!(edge.node1_pub, "02cccc");
+    assert_eq!(edge.chan_point, "bbaa:1");
+    assert!(edge.node2_policy.is_some());
+    assert_eq!(cache.node_edges("03bbbb").count(), 2);
+
+    cache.apply(&updates.next().await.unwrap().unwrap());
+    assert!(cache.edge("880000000000000000").is_none());
+    assert_eq!(cache.edges().count(), 1);
+}
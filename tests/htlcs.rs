@@ -0,0 +1,78 @@
+mod common;
+
+use common::{route, MockServer, CERT_PATH, MACAROON_PATH};
+use futures_util::StreamExt;
+use lnd_rest::node::Node;
+use lnd_rest::types::{FailureCode, FailureDetail, HtlcEventKind, HtlcEventType};
+
+const INFO: &str = r#"{"incoming_timelock": 800080, "outgoing_timelock": 800040, "incoming_amt_msat": "100001000", "outgoing_amt_msat": "100000000"}"#;
+
+fn event(event_type: &str, event: &str) -> String {
+    format!(
+        r#"{{"result": {{"incoming_channel_id": "880000000000000000", "outgoing_channel_id": "890000000000000000", "incoming_htlc_id": "7", "outgoing_htlc_id": "3", "timestamp_ns": "1690000000000000000", "event_type": "{event_type}", {event}}}}}"#
+    )
+}
+
+#[tokio::test]
+async fn htlc_events_are_typed() {
+    let events = [
+        event("UNKNOWN", r#""subscribed_event": {}"#),
+        event("FORWARD", &format!(r#""forward_event": {{"info": {INFO}}}"#)),
+        event(
+            "FORWARD",
+            &format!(
+                r#""link_fail_event": {{"info": {INFO}, "wire_failure": "TEMPORARY_CHANNEL_FAILURE", "failure_detail": "INSUFFICIENT_BALANCE", "failure_string": "insufficient bandwidth to route htlc"}}"#
+            ),
+        ),
+        event("FORWARD", r#""forward_fail_event": {}"#),
+        event("FORWARD", r#""settle_event": {"preimage": "u7s="}"#),
+        event(
+            "UNKNOWN",
+            r#""final_htlc_event": {"settled": true, "offchain": true}"#,
+        ),
+        event(
+            "RECEIVE",
+            &format!(
+                r#""link_fail_event": {{"info": {INFO}, "wire_failure": "INVALID_BLINDING", "failure_detail": "INVALID_BLINDED_ROUTE", "failure_string": "invalid blinding"}}"#
+            ),
+        ),
+    ]
+    .join("\n");
+    let server = MockServer::start(vec![route("GET", "/v2/router/htlcevents", &events)]).await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let events: Vec<_> = node
+        .subscribe_htlc_events()
+        .await
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 7);
+    assert_eq!(events[0].event, HtlcEventKind::Subscribed);
+    assert_eq!(events[1].event_type, HtlcEventType::FORWARD);
+    assert_eq!(events[1].incoming_htlc_id, "7");
+    match &events[2].event {
+        HtlcEventKind::LinkFail(event) => {
+            assert_eq!(event.wire_failure, FailureCode::TEMPORARY_CHANNEL_FAILURE);
+            assert_eq!(event.failure_detail, FailureDetail::INSUFFICIENT_BALANCE);
+            assert_eq!(event.info.outgoing_amt_msat, "100000000");
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+    assert_eq!(events[3].event, HtlcEventKind::ForwardFail);
+    assert!(matches!(events[4].event, HtlcEventKind::Settle(_)));
+    assert!(matches!(
+        &events[5].event,
+        HtlcEventKind::FinalHtlc(event) if event.settled
+    ));
+    assert!(matches!(
+        &events[6].event,
+        HtlcEventKind::LinkFail(event)
+            if event.wire_failure == FailureCode::Unknown
+                && event.failure_detail == FailureDetail::Unknown
+    ));
+}
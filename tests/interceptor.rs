@@ -0,0 +1,168 @@
+#![cfg(feature = "websocket")]
+
+mod common;
+
+use common::{WebSocketServer, CERT_PATH, MACAROON_PATH};
+use futures_util::{SinkExt, StreamExt};
+use lnd_rest::node::{Node, NodeError, NodeOptions};
+use lnd_rest::types::{FailureCode, ForwardHtlcInterceptResponse};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const INTERCEPTED: &str = r#"{"result": {
+    "incoming_circuit_key": {"chan_id": "880000000000000000", "htlc_id": "7"},
+    "incoming_amount_msat": "100001000",
+    "incoming_expiry": 800080,
+    "payment_hash": "zM0=",
+    "outgoing_requested_chan_id": "890000000000000000",
+    "outgoing_amount_msat": "100000000",
+    "outgoing_expiry": 800040,
+    "custom_records": {},
+    "onion_blob": "AAAA",
+    "auto_fail_height": 800070
+}}"#;
+
+/// Accept a single SOCKS5 connection and forward it to the requested port on the loopback interface. Return the
+/// target host name sent by the client.
+async fn forward_socks5_once(listener: TcpListener) -> String {
+    let (mut stream, _) = listener.accept().await.unwrap();
+
+    let mut greeting = [0u8; 2];
+    stream.read_exact(&mut greeting).await.unwrap();
+    let mut methods = vec![0u8; greeting[1] as usize];
+    stream.read_exact(&mut methods).await.unwrap();
+    stream.write_all(&[5, 0]).await.unwrap();
+
+    let mut request = [0u8; 4];
+    stream.read_exact(&mut request).await.unwrap();
+    assert_eq!(request[3], 3, "target must be resolved by the proxy");
+    let mut len = [0u8; 1];
+    stream.read_exact(&mut len).await.unwrap();
+    let mut domain = vec![0u8; len[0] as usize];
+    stream.read_exact(&mut domain).await.unwrap();
+    let mut port = [0u8; 2];
+    stream.read_exact(&mut port).await.unwrap();
+
+    let mut target = TcpStream::connect(("127.0.0.1", u16::from_be_bytes(port)))
+        .await
+        .unwrap();
+    stream
+        .write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0])
+        .await
+        .unwrap();
+
+    let domain = String::from_utf8(domain).unwrap();
+    tokio::spawn(async move {
+        let _ = tokio::io::copy_bidirectional(&mut stream, &mut target).await;
+    });
+
+    domain
+}
+
+#[tokio::test]
+async fn htlcs_are_intercepted_and_resolved() {
+    let server = WebSocketServer::start(vec![INTERCEPTED.to_string()]).await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let (mut resolutions, mut htlcs) = node.htlc_interceptor().await.unwrap();
+
+    let htlc = htlcs.next().await.unwrap().unwrap();
+    assert_eq!(htlc.outgoing_requested_chan_id, "890000000000000000");
+    assert_eq!(htlc.auto_fail_height, 800070);
+
+    resolutions
+        .send(ForwardHtlcInterceptResponse::fail(
+            htlc.incoming_circuit_key,
+            FailureCode::TEMPORARY_CHANNEL_FAILURE,
+        ))
+        .await
+        .unwrap();
+    assert!(htlcs.next().await.is_none());
+
+    let (path, headers) = server.request.lock().unwrap().clone().unwrap();
+    assert_eq!(path, "/v2/router/htlcinterceptor?method=POST");
+    assert!(headers.contains(&"grpc-metadata-macaroon".to_string()));
+
+    let received = server.received.lock().unwrap().clone();
+    let resolution: serde_json::Value = serde_json::from_str(&received[0]).unwrap();
+    assert_eq!(
+        resolution,
+        serde_json::json!({
+            "incoming_circuit_key": {"chan_id": "880000000000000000", "htlc_id": "7"},
+            "action": "FAIL",
+            "failure_code": "TEMPORARY_CHANNEL_FAILURE"
+        })
+    );
+}
+
+#[tokio::test]
+async fn interceptor_is_routed_through_socks5_proxy() {
+    let server = WebSocketServer::start(vec![INTERCEPTED.to_string()]).await;
+    let port = server.url.rsplit(':').next().unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy = listener.local_addr().unwrap().to_string();
+    let forwarded = tokio::spawn(forward_socks5_once(listener));
+
+    #[allow(clippy::needless_update)]
+    let options = NodeOptions {
+        proxy: Some(proxy),
+        ..Default::default()
+    };
+    let node = Node::init_with_options(
+        format!("http://localhost:{port}"),
+        MACAROON_PATH,
+        CERT_PATH,
+        &options,
+    )
+    .await
+    .unwrap();
+
+    let (mut resolutions, mut htlcs) = node.htlc_interceptor().await.unwrap();
+    let htlc = htlcs.next().await.unwrap().unwrap();
+    resolutions
+        .send(ForwardHtlcInterceptResponse::resume(
+            htlc.incoming_circuit_key,
+        ))
+        .await
+        .unwrap();
+    assert!(htlcs.next().await.is_none());
+
+    assert_eq!(forwarded.await.unwrap(), "localhost");
+    assert_eq!(server.received.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn interceptor_requires_an_http_host() {
+    let node = Node::init("ftp://127.0.0.1:8080".to_string(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        node.htlc_interceptor().await,
+        Err(NodeError::InvalidUrl(_))
+    ));
+}
+
+#[tokio::test]
+async fn interceptor_connects_to_ipv6_hosts() {
+    let server = WebSocketServer::start_on("[::1]:0", vec![INTERCEPTED.to_string()]).await;
+    assert!(server.url.starts_with("http://[::1]:"));
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let (mut responses, mut requests) = node.htlc_interceptor().await.unwrap();
+    let request = requests.next().await.unwrap().unwrap();
+    responses
+        .send(ForwardHtlcInterceptResponse::resume(
+            request.incoming_circuit_key,
+        ))
+        .await
+        .unwrap();
+    while requests.next().await.is_some() {}
+
+    assert_eq!(server.received.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::{node, route, MockServer};
+use lnd_rest::types::{
+    BimodalParameters, MissionControlConfig, PairData, PairHistory, ProbabilityModel,
+    SetMissionControlConfigRequest, XImportMissionControlRequest,
+};
+
+const PAIRS: &str = r#"{
+    "pairs": [{
+        "node_from": "Aqqq",
+        "node_to": "A7u7",
+        "history": {
+            "fail_time": "1690000000",
+            "fail_amt_sat": "50000",
+            "fail_amt_msat": "50000000",
+            "success_time": "0",
+            "success_amt_sat": "0",
+            "success_amt_msat": "0"
+        }
+    }]
+}"#;
+
+const CONFIG: &str = r#"{
+    "config": {
+        "half_life_seconds": "0",
+        "hop_probability": 0,
+        "weight": 0,
+        "maximum_payment_results": 1000,
+        "minimum_failure_relax_interval": "60",
+        "model": "BIMODAL",
+        "bimodal": {"node_weight": 0.2, "scale_msat": "300000000", "decay_time": "604800"}
+    }
+}"#;
+
+#[tokio::test]
+async fn mission_control_is_queried_reset_and_imported() {
+    let probability = r#"{
+        "probability": 0.4,
+        "history": {
+            "fail_time": "1690000000",
+            "fail_amt_sat": "50000",
+            "fail_amt_msat": "50000000",
+            "success_time": "0",
+            "success_amt_sat": "0",
+            "success_amt_msat": "0"
+        }
+    }"#;
+    let server = MockServer::start(vec![
+        route("GET", "/v2/router/mc", PAIRS),
+        route(
+            "GET",
+            "/v2/router/mc/probability/A_-_/Aqqq/1000000",
+            probability,
+        ),
+        route("POST", "/v2/router/mc/reset", "{}"),
+        route("POST", "/v2/router/x/importhistory", "{}"),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let pairs = node.query_mission_control().await.unwrap().pairs;
+    assert_eq!(pairs[0].history.fail_amt_sat, "50000");
+
+    let response = node
+        .query_probability("A/+/", "Aqqq", 1000000)
+        .await
+        .unwrap();
+    assert_eq!(response.probability, 0.4);
+
+    node.reset_mission_control().await.unwrap();
+
+    let x_import_mission_control_request = XImportMissionControlRequest {
+        pairs: vec![PairHistory {
+            node_from: "Aqqq".to_string(),
+            node_to: "A7u7".to_string(),
+            history: PairData {
+                success_time: "1690000000".to_string(),
+                success_amt_msat: "10000000".to_string(),
+                ..Default::default()
+            },
+        }],
+        force: true,
+    };
+    node.x_import_mission_control(&x_import_mission_control_request)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    let body: serde_json::Value = serde_json::from_str(&requests[3].body).unwrap();
+    assert_eq!(body["force"], true);
+    assert_eq!(body["pairs"][0]["history"]["success_amt_msat"], "10000000");
+}
+
+#[tokio::test]
+async fn mission_control_config_is_read_and_updated() {
+    let server = MockServer::start(vec![
+        route("GET", "/v2/router/mccfg", CONFIG),
+        route("POST", "/v2/router/mccfg", "{}"),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let config = node.get_mission_control_config().await.unwrap().config;
+    assert_eq!(config.model, ProbabilityModel::BIMODAL);
+    assert_eq!(config.bimodal.as_ref().unwrap().scale_msat, "300000000");
+    assert!(config.apriori.is_none());
+
+    let set_mission_control_config_request = SetMissionControlConfigRequest {
+        config: MissionControlConfig {
+            bimodal: Some(BimodalParameters {
+                decay_time: "86400".to_string(),
+                ..config.bimodal.unwrap()
+            }),
+            ..config
+        },
+    };
+    node.set_mission_control_config(&set_mission_control_config_request)
+        .await
+        .unwrap();
+
+    let body: serde_json::Value = serde_json::from_str(&server.requests()[1].body).unwrap();
+    assert_eq!(body["config"]["model"], "BIMODAL");
+    assert_eq!(body["config"]["bimodal"]["decay_time"], "86400");
+    assert!(body["config"].get("apriori").is_none());
+}
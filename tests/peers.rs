@@ -0,0 +1,107 @@
+mod common;
+
+use common::{route, MockServer, CERT_PATH, MACAROON_PATH};
+use futures_util::StreamExt;
+use lnd_rest::node::{Node, NodeError};
+use lnd_rest::types::{ConnectPeerRequest, PeerEventType, SyncType};
+
+const PEERS: &str = r#"{
+    "peers": [{
+        "pub_key": "03aaaa",
+        "address": "10.0.0.1:9735",
+        "bytes_sent": "1024",
+        "bytes_recv": "2048",
+        "sat_sent": "0",
+        "sat_recv": "0",
+        "inbound": false,
+        "ping_time": "1500",
+        "sync_type": "ACTIVE_SYNC",
+        "features": {},
+        "errors": [{"timestamp": "1690000000", "error": "ping timeout"}],
+        "flap_count": 2,
+        "last_flap_ns": "1690000000000000000",
+        "last_ping_payload": ""
+    }]
+}"#;
+
+const PEER_EVENTS: &str = concat!(
+    r#"{"result": {"pub_key": "03aaaa", "type": "PEER_OFFLINE"}}"#,
+    "\n",
+    r#"{"result": {"pub_key": "03aaaa", "type": "PEER_ONLINE"}}"#,
+    "\n",
+    r#"{"error": {"code": 1, "message": "context canceled", "details": []}}"#,
+    "\n",
+);
+
+#[tokio::test]
+async fn peers_are_listed_connected_and_disconnected() {
+    let server = MockServer::start(vec![
+        route("GET", "/v1/peers", PEERS),
+        route("POST", "/v1/peers", "{}"),
+        route("DELETE", "/v1/peers/03aaaa", "{}"),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let peers = node.list_peers(true).await.unwrap().peers;
+    assert_eq!(peers[0].sync_type, SyncType::ACTIVE_SYNC);
+    assert_eq!(peers[0].flap_count, 2);
+    assert_eq!(peers[0].errors[0].error, "ping timeout");
+
+    let connect_peer_request = ConnectPeerRequest {
+        addr: "03aaaa@10.0.0.1:9735".parse().unwrap(),
+        perm: true,
+        timeout: 30,
+    };
+    node.connect_peer(&connect_peer_request).await.unwrap();
+    node.disconnect_peer(&"03aaaa".to_string()).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/v1/peers?latest_error=true");
+    let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "addr": {"pubkey": "03aaaa", "host": "10.0.0.1:9735"},
+            "perm": true,
+            "timeout": 30
+        })
+    );
+    assert_eq!(requests[2].method, "DELETE");
+}
+
+#[tokio::test]
+async fn peer_events_are_streamed() {
+    let server = MockServer::start(vec![route("GET", "/v1/peers/subscribe", PEER_EVENTS)]).await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let events: Vec<_> = node.subscribe_peer_events().await.unwrap().collect().await;
+
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        events[0].as_ref().unwrap().event_type,
+        PeerEventType::PEER_OFFLINE
+    );
+    assert_eq!(
+        events[1].as_ref().unwrap().event_type,
+        PeerEventType::PEER_ONLINE
+    );
+    assert!(matches!(
+        events[2],
+        Err(NodeError::ApiError { code: 1, .. })
+    ));
+}
+
+#[test]
+fn lightning_address_requires_pubkey_and_host() {
+    assert!("03aaaa"
+        .parse::<lnd_rest::types::LightningAddress>()
+        .is_err());
+    assert!("@10.0.0.1"
+        .parse::<lnd_rest::types::LightningAddress>()
+        .is_err());
+}
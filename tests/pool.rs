@@ -0,0 +1,105 @@
+mod common;
+
+use common::{route, MockServer, CERT_PATH, MACAROON_PATH};
+use lnd_rest::node::Node;
+use lnd_rest::pool::{NodePool, ReadStrategy};
+use lnd_rest::types::AddInvoiceRequest;
+use tokio::net::TcpListener;
+
+const ADD_INVOICE: &str = r#"{
+    "r_hash": "aGFzaA==",
+    "payment_request": "lnbc10n1",
+    "add_index": "1",
+    "payment_addr": "YWRkcg=="
+}"#;
+
+async fn node(host: String) -> Node {
+    Node::init(host, MACAROON_PATH, CERT_PATH).await.unwrap()
+}
+
+/// Return the address of a port nothing listens on.
+async fn closed_address() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    format!("http://{}", listener.local_addr().unwrap())
+}
+
+#[tokio::test]
+async fn reads_skip_unsynced_nodes() {
+    let unsynced = MockServer::start(vec![route(
+        "GET",
+        "/v1/getinfo",
+        &common::get_info("unsynced", false),
+    )])
+    .await;
+    let synced = MockServer::start(vec![route(
+        "GET",
+        "/v1/getinfo",
+        &common::get_info("synced", true),
+    )])
+    .await;
+
+    let pool = NodePool::new(
+        node(unsynced.url.clone()).await,
+        vec![node(synced.url.clone()).await],
+        ReadStrategy::RoundRobin,
+    );
+    pool.check_health().await;
+
+    let health = pool.health();
+    assert!(!health[0].1.healthy);
+    assert!(health[1].1.healthy);
+
+    for _ in 0..3 {
+        assert_eq!(pool.get_info().await.unwrap().alias, "synced");
+    }
+}
+
+#[tokio::test]
+async fn reads_are_balanced_round_robin() {
+    let first = MockServer::start(vec![route(
+        "GET",
+        "/v1/getinfo",
+        &common::get_info("first", true),
+    )])
+    .await;
+    let second = MockServer::start(vec![route(
+        "GET",
+        "/v1/getinfo",
+        &common::get_info("second", true),
+    )])
+    .await;
+
+    let pool = NodePool::new(
+        node(first.url.clone()).await,
+        vec![node(second.url.clone()).await],
+        ReadStrategy::RoundRobin,
+    );
+
+    let mut aliases = Vec::new();
+    for _ in 0..4 {
+        aliases.push(pool.get_info().await.unwrap().alias);
+    }
+    assert_eq!(aliases, ["first", "second", "first", "second"]);
+}
+
+#[tokio::test]
+async fn writes_fail_over_when_primary_is_unreachable() {
+    let fallback = MockServer::start(vec![route("POST", "/v1/invoices", ADD_INVOICE)]).await;
+
+    let pool = NodePool::new(
+        node(closed_address().await).await,
+        vec![node(fallback.url.clone()).await],
+        ReadStrategy::RoundRobin,
+    );
+
+    let invoice = pool
+        .add_invoice(&AddInvoiceRequest {
+            value_msat: 1000,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(invoice.payment_request, "lnbc10n1");
+    assert_eq!(fallback.requests().len(), 1);
+    assert!(!pool.health()[0].1.healthy);
+}
@@ -0,0 +1,108 @@
+use lnd_rest::node::{Node, NodeOptions};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const ONION_HOST: &str = "lndrestexampleonionaddress.onion";
+const ONION_PORT: u16 = 8080;
+
+const INVOICE: &str = r#"{
+    "memo": "coffee",
+    "r_preimage": "",
+    "r_hash": "",
+    "value": "1",
+    "value_msat": "1000",
+    "settled": false,
+    "settle_date": "0",
+    "creation_date": "1690000000",
+    "payment_request": "lnbc10n1",
+    "expiry": "3600",
+    "state": "OPEN"
+}"#;
+
+/// Accept a single SOCKS5 connection, check that the target was sent as a domain name, then answer the
+/// tunnelled HTTP request. Return the target and the raw HTTP request.
+async fn serve_socks5_once(listener: TcpListener) -> (String, u16, String) {
+    let (mut stream, _) = listener.accept().await.unwrap();
+
+    // Greeting: version, number of methods, methods.
+    let mut greeting = [0u8; 2];
+    stream.read_exact(&mut greeting).await.unwrap();
+    assert_eq!(greeting[0], 5);
+    let mut methods = vec![0u8; greeting[1] as usize];
+    stream.read_exact(&mut methods).await.unwrap();
+    assert!(methods.contains(&0));
+    stream.write_all(&[5, 0]).await.unwrap();
+
+    // Request: version, CONNECT, reserved, address type.
+    let mut request = [0u8; 4];
+    stream.read_exact(&mut request).await.unwrap();
+    assert_eq!(request[..2], [5, 1]);
+    assert_eq!(request[3], 3, "target must be resolved by the proxy");
+    let mut len = [0u8; 1];
+    stream.read_exact(&mut len).await.unwrap();
+    let mut domain = vec![0u8; len[0] as usize];
+    stream.read_exact(&mut domain).await.unwrap();
+    let mut port = [0u8; 2];
+    stream.read_exact(&mut port).await.unwrap();
+    stream
+        .write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0])
+        .await
+        .unwrap();
+
+    let http_request = read_http_request(&mut stream).await;
+    let http_response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        INVOICE.len(),
+        INVOICE
+    );
+    stream.write_all(http_response.as_bytes()).await.unwrap();
+
+    (
+        String::from_utf8(domain).unwrap(),
+        u16::from_be_bytes(port),
+        http_request,
+    )
+}
+
+async fn read_http_request(stream: &mut TcpStream) -> String {
+    let mut data = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !data.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await.unwrap();
+        assert!(n > 0, "connection closed before end of request");
+        data.extend_from_slice(&buf[..n]);
+    }
+    String::from_utf8(data).unwrap()
+}
+
+#[tokio::test]
+async fn requests_are_routed_through_socks5_proxy() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy = listener.local_addr().unwrap().to_string();
+    let server = tokio::spawn(serve_socks5_once(listener));
+
+    #[allow(clippy::needless_update)]
+    let options = NodeOptions {
+        proxy: Some(proxy),
+        ..Default::default()
+    };
+    let node = Node::init_with_options(
+        format!("http://{ONION_HOST}:{ONION_PORT}"),
+        "tests/data/admin.macaroon",
+        "tests/data/tls.cert",
+        &options,
+    )
+    .await
+    .unwrap();
+
+    let invoice = node.lookup_invoice(&"abcd".to_string()).await.unwrap();
+    assert_eq!(invoice.memo, "coffee");
+
+    let (domain, port, http_request) = server.await.unwrap();
+    assert_eq!(domain, ONION_HOST);
+    assert_eq!(port, ONION_PORT);
+    assert!(http_request.starts_with("GET /v1/invoice/abcd HTTP/1.1\r\n"));
+    assert!(http_request
+        .to_lowercase()
+        .contains("grpc-metadata-macaroon: "));
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use common::{route, MockServer, CERT_PATH, MACAROON_PATH};
+use lnd_rest::node::{Node, NodeError};
+use lnd_rest::psbt::PsbtChannelOpen;
+use lnd_rest::types::{OpenChannelRequest, OpenStatusUpdateKind, PendingUpdate};
+
+const PENDING_CHAN_ID: [u8; 32] = [7; 32];
+const PENDING_CHAN_ID_BASE64: &str = "BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwc=";
+
+fn open_channel_updates() -> String {
+    [
+        format!(
+            r#"{{"result": {{"pending_chan_id": "{PENDING_CHAN_ID_BASE64}", "psbt_fund": {{"funding_address": "bc1qfunding", "funding_amount": "1000000", "psbt": "cHNidP8B"}}}}}}"#
+        ),
+        format!(
+            r#"{{"result": {{"pending_chan_id": "{PENDING_CHAN_ID_BASE64}", "chan_pending": {{"txid": "q80=", "output_index": 1}}}}}}"#
+        ),
+    ]
+    .join("\n")
+}
+
+fn open_channel_request() -> OpenChannelRequest {
+    OpenChannelRequest {
+        node_pubkey: "AqqqAA==".to_string(),
+        local_funding_amount: 1_000_000,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn psbt_channel_open_walks_the_funding_steps() {
+    let server = MockServer::start(vec![
+        route("POST", "/v1/channels/stream", &open_channel_updates()),
+        route("POST", "/v1/funding/step", "{}"),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let psbt_channel_open = PsbtChannelOpen::new(&node, open_channel_request(), PENDING_CHAN_ID);
+    assert_eq!(psbt_channel_open.pending_chan_id(), PENDING_CHAN_ID_BASE64);
+
+    let pending = psbt_channel_open
+        .run(
+            |ready| async move {
+                assert_eq!(ready.funding_address, "bc1qfunding");
+                assert_eq!(ready.funding_amount, "1000000");
+                Ok("ZnVuZGVk".to_string())
+            },
+            |funded_psbt| async move {
+                assert_eq!(funded_psbt, "ZnVuZGVk");
+                Ok("c2lnbmVk".to_string())
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        pending,
+        PendingUpdate {
+            txid: "q80=".to_string(),
+            output_index: 1,
+        }
+    );
+
+    let requests = server.requests();
+    let bodies: Vec<serde_json::Value> = requests
+        .iter()
+        .map(|request| serde_json::from_str(&request.body).unwrap())
+        .collect();
+    assert_eq!(requests[0].path, "/v1/channels/stream");
+    assert_eq!(
+        bodies[0]["funding_shim"],
+        serde_json::json!({"psbt_shim": {"pending_chan_id": PENDING_CHAN_ID_BASE64, "base_psbt": "", "no_publish": false}})
+    );
+    assert_eq!(
+        bodies[1],
+        serde_json::json!({"psbt_verify": {"funded_psbt": "ZnVuZGVk", "pending_chan_id": PENDING_CHAN_ID_BASE64, "skip_finalize": false}})
+    );
+    assert_eq!(
+        bodies[2],
+        serde_json::json!({"psbt_finalize": {"signed_psbt": "c2lnbmVk", "pending_chan_id": PENDING_CHAN_ID_BASE64}})
+    );
+    assert_eq!(requests.len(), 3);
+}
+
+#[tokio::test]
+async fn psbt_channel_open_cancels_the_shim_when_signing_fails() {
+    let server = MockServer::start(vec![
+        route("POST", "/v1/channels/stream", &open_channel_updates()),
+        route("POST", "/v1/funding/step", "{}"),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let result = PsbtChannelOpen::new(&node, open_channel_request(), PENDING_CHAN_ID)
+        .run(
+            |_| async { Ok("ZnVuZGVk".to_string()) },
+            |_| async {
+                Err(NodeError::IoError(std::io::Error::other(
+                    "signer unavailable",
+                )))
+            },
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(NodeError::IoError(err)) if err.to_string() == "signer unavailable")
+    );
+
+    let requests = server.requests();
+    let last: serde_json::Value = serde_json::from_str(&requests.last().unwrap().body).unwrap();
+    assert_eq!(
+        last,
+        serde_json::json!({"shim_cancel": {"pending_chan_id": PENDING_CHAN_ID_BASE64}})
+    );
+}
+
+#[tokio::test]
+async fn psbt_channel_open_rejects_out_of_order_updates() {
+    let updates = open_channel_updates();
+    let chan_pending = updates.lines().last().unwrap();
+    let server = MockServer::start(vec![
+        route("POST", "/v1/channels/stream", chan_pending),
+        route("POST", "/v1/funding/step", "{}"),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    let result = PsbtChannelOpen::new(&node, open_channel_request(), PENDING_CHAN_ID)
+        .run(
+            |_| async { panic!("the node did not ask for a PSBT") },
+            |_| async { panic!("the node did not ask for a PSBT") },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(NodeError::UnexpectedUpdate(update)) if matches!(*update, OpenStatusUpdateKind::ChanPending(_))
+    ));
+    assert_eq!(server.requests().len(), 2);
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use common::{node, route, MockServer};
+use lnd_rest::types::{
+    BuildRouteRequest, FeeLimit, HTLCStatus, HopHint, NodePair, QueryRoutesRequest, RouteHint,
+    SendToRouteRequest,
+};
+
+const ROUTE: &str = r#"{
+    "total_time_lock": 800080,
+    "total_fees": "1",
+    "total_amt": "100001",
+    "hops": [{
+        "chan_id": "880000000000000000",
+        "chan_capacity": "1000000",
+        "amt_to_forward": "100000",
+        "fee": "1",
+        "expiry": 800040,
+        "amt_to_forward_msat": "100000000",
+        "fee_msat": "1000",
+        "pub_key": "03bbbb",
+        "tlv_payload": true,
+        "mpp_record": null,
+        "amp_record": null,
+        "custom_records": {},
+        "metadata": ""
+    }, {
+        "chan_id": "890000000000000000",
+        "chan_capacity": "2000000",
+        "amt_to_forward": "100000",
+        "fee": "0",
+        "expiry": 800040,
+        "amt_to_forward_msat": "100000000",
+        "fee_msat": "0",
+        "pub_key": "02aaaa",
+        "tlv_payload": true,
+        "mpp_record": {"payment_addr": "qrs=", "total_amt_msat": "100000000"},
+        "amp_record": null,
+        "custom_records": {},
+        "metadata": ""
+    }],
+    "total_fees_msat": "1000",
+    "total_amt_msat": "100001000"
+}"#;
+
+#[tokio::test]
+async fn routes_are_queried() {
+    let routes = format!(r#"{{"routes": [{ROUTE}], "success_prob": 0.85}}"#);
+    let server = MockServer::start(vec![
+        route("GET", "/v1/graph/routes/02aaaa/100000", &routes),
+        route("POST", "/v1/graph/routes", &routes),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let mut query_routes_request = QueryRoutesRequest {
+        pub_key: "02aaaa".to_string(),
+        amt: 100000,
+        fee_limit: Some(FeeLimit {
+            fixed: Some("10".to_string()),
+            fixed_msat: None,
+            percent: None,
+        }),
+        ignored_nodes: vec!["A8zM".to_string(), "A93d".to_string()],
+        use_mission_control: true,
+        outgoing_chan_id: Some("880000000000000000".to_string()),
+        ..Default::default()
+    };
+    let response = node.query_routes(&query_routes_request).await.unwrap();
+    assert_eq!(response.success_prob, 0.85);
+    assert_eq!(response.routes[0].hops.len(), 2);
+
+    query_routes_request.ignored_pairs = vec![NodePair {
+        from: "A7u7".to_string(),
+        to: "AqpK".to_string(),
+    }];
+    query_routes_request.route_hints = vec![RouteHint {
+        hop_hints: vec![HopHint {
+            node_id: "03cccc".to_string(),
+            chan_id: "900000000000000000".to_string(),
+            fee_base_msat: 1000,
+            fee_proportional_millionths: 1,
+            cltv_expiry_delta: 40,
+        }],
+    }];
+    node.query_routes(&query_routes_request).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/v1/graph/routes/02aaaa/100000?fee_limit.fixed=10&ignored_nodes=A8zM&ignored_nodes=A93d\
+         &use_mission_control=true&outgoing_chan_id=880000000000000000"
+    );
+    let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+    assert_eq!(body["pub_key"], "02aaaa");
+    assert_eq!(body["ignored_pairs"][0]["to"], "AqpK");
+    assert_eq!(
+        body["route_hints"][0]["hop_hints"][0]["chan_id"],
+        "900000000000000000"
+    );
+}
+
+#[tokio::test]
+async fn routes_are_built_and_paid() {
+    let attempt = format!(
+        r#"{{
+            "attempt_id": "1",
+            "status": "SUCCEEDED",
+            "route": {ROUTE},
+            "attempt_time_ns": "1690000000000000000",
+            "resolve_time_ns": "1690000001000000000",
+            "failure": null,
+            "preimage": "u7s="
+        }}"#
+    );
+    let server = MockServer::start(vec![
+        route(
+            "POST",
+            "/v2/router/route",
+            &format!(r#"{{"route": {ROUTE}}}"#),
+        ),
+        route("POST", "/v2/router/route/send", &attempt),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let build_route_request = BuildRouteRequest {
+        amt_msat: 100000000,
+        final_cltv_delta: 40,
+        outgoing_chan_id: Some("880000000000000000".to_string()),
+        hop_pubkeys: vec!["A7u7".to_string(), "AqpK".to_string()],
+        ..Default::default()
+    };
+    let route = node.build_route(&build_route_request).await.unwrap().route;
+
+    let send_to_route_request = SendToRouteRequest {
+        payment_hash: "zM0=".to_string(),
+        route,
+        skip_temp_err: false,
+    };
+    let attempt = node.send_to_route(&send_to_route_request).await.unwrap();
+    assert_eq!(attempt.status, HTLCStatus::SUCCEEDED);
+
+    let requests = server.requests();
+    let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(body["hop_pubkeys"][1], "AqpK");
+    let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+    let hops = &body["route"]["hops"];
+    assert_eq!(hops[0]["chan_id"], "880000000000000000");
+    assert!(hops[0].get("mpp_record").is_none());
+    assert_eq!(hops[1]["mpp_record"]["payment_addr"], "qrs=");
+}
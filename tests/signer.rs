@@ -0,0 +1,109 @@
+mod common;
+
+use common::{node, route, MockServer};
+use lnd_rest::types::{
+    KeyDescriptor, KeyLocator, SharedKeyRequest, SignMessageReq, SignMessageRequest,
+    VerifyMessageReq, VerifyMessageRequest,
+};
+
+#[tokio::test]
+async fn messages_are_signed_and_verified_with_the_node_key() {
+    let server = MockServer::start(vec![
+        route("POST", "/v1/signmessage", r#"{"signature": "d9xa1y3ds"}"#),
+        route(
+            "POST",
+            "/v1/verifymessage",
+            r#"{"valid": true, "pubkey": "02aaaa"}"#,
+        ),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let signed = node
+        .sign_message(&SignMessageRequest::new(b"login:k1"))
+        .await
+        .unwrap();
+    assert_eq!(signed.signature, "d9xa1y3ds");
+
+    let verified = node
+        .verify_message(&VerifyMessageRequest::new(b"login:k1", &signed.signature))
+        .await
+        .unwrap();
+    assert!(verified.valid);
+    assert_eq!(verified.pubkey, "02aaaa");
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].body,
+        r#"{"msg":"bG9naW46azE=","single_hash":false}"#
+    );
+    assert_eq!(
+        requests[1].body,
+        r#"{"msg":"bG9naW46azE=","signature":"d9xa1y3ds"}"#
+    );
+}
+
+#[tokio::test]
+async fn messages_are_signed_and_verified_with_wallet_keys() {
+    let server = MockServer::start(vec![
+        route("POST", "/v2/signer/signmessage", r#"{"signature": "MEQ="}"#),
+        route("POST", "/v2/signer/verifymessage", r#"{"valid": false}"#),
+        route(
+            "POST",
+            "/v2/signer/sharedkey",
+            r#"{"shared_key": "c2hhcmVk"}"#,
+        ),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let key_loc = KeyLocator {
+        key_family: 138,
+        key_index: 1,
+    };
+    let sign_message_req = SignMessageReq {
+        compact_sig: true,
+        ..SignMessageReq::new(b"proof", key_loc.clone())
+    };
+    let signed = node
+        .sign_message_with_key_locator(&sign_message_req)
+        .await
+        .unwrap();
+    assert_eq!(signed.signature, "MEQ=");
+
+    let verified = node
+        .verify_message_with_pubkey(&VerifyMessageReq::new(b"proof", &[0x30, 0x44], &[2, 0xaa]))
+        .await
+        .unwrap();
+    assert!(!verified.valid);
+
+    let shared_key_request = SharedKeyRequest {
+        key_desc: Some(KeyDescriptor {
+            key_loc,
+            ..Default::default()
+        }),
+        ..SharedKeyRequest::new(&[3, 0xbb])
+    };
+    let shared = node.derive_shared_key(&shared_key_request).await.unwrap();
+    assert_eq!(shared.shared_key, "c2hhcmVk");
+
+    let bodies: Vec<serde_json::Value> = server
+        .requests()
+        .iter()
+        .map(|request| serde_json::from_str(&request.body).unwrap())
+        .collect();
+    assert_eq!(bodies[0]["msg"], "cHJvb2Y=");
+    assert_eq!(bodies[0]["key_loc"]["key_family"], 138);
+    assert_eq!(bodies[0]["compact_sig"], true);
+    assert_eq!(
+        bodies[1],
+        serde_json::json!({"msg": "cHJvb2Y=", "signature": "MEQ=", "pubkey": "Aqo=", "is_schnorr_sig": false})
+    );
+    assert_eq!(
+        bodies[2],
+        serde_json::json!({
+            "ephemeral_pubkey": "A7s=",
+            "key_desc": {"raw_key_bytes": "", "key_loc": {"key_family": 138, "key_index": 1}}
+        })
+    );
+}
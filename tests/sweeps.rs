@@ -0,0 +1,174 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{get_info, node, route, MockServer};
+use lnd_rest::node::NodeError;
+use lnd_rest::types::{
+    BumpFeeRequest, ListSweepsRequest, ListSweepsResponse, OutPoint, WitnessType,
+};
+use lnd_rest::withdrawals::StuckWithdrawal;
+
+const PENDING_SWEEPS: &str = r#"{
+    "pending_sweeps": [{
+        "outpoint": {"txid_bytes": "qqo=", "txid_str": "aaaa", "output_index": 0},
+        "witness_type": "COMMITMENT_ANCHOR",
+        "amount_sat": 330,
+        "sat_per_byte": 0,
+        "broadcast_attempts": 2,
+        "next_broadcast_height": 800010,
+        "requested_conf_target": 6,
+        "requested_sat_per_byte": 0,
+        "sat_per_vbyte": "12",
+        "requested_sat_per_vbyte": "0",
+        "force": false
+    }, {
+        "outpoint": {"txid_bytes": "u7s=", "txid_str": "bbbb", "output_index": 1},
+        "witness_type": "TAPROOT_HTLC_OFFERED_REMOTE_TIMEOUT",
+        "amount_sat": 20000,
+        "sat_per_byte": 0,
+        "broadcast_attempts": 0,
+        "next_broadcast_height": 800144,
+        "requested_conf_target": 0,
+        "requested_sat_per_byte": 0,
+        "sat_per_vbyte": "0",
+        "requested_sat_per_vbyte": "0",
+        "force": false
+    }]
+}"#;
+
+fn transaction(tx_hash: &str, amount: i64, num_confirmations: i32) -> String {
+    format!(
+        r#"{{
+            "tx_hash": "{tx_hash}",
+            "amount": "{amount}",
+            "num_confirmations": {num_confirmations},
+            "block_hash": "",
+            "block_height": 0,
+            "time_stamp": "1690000000",
+            "total_fees": "141",
+            "dest_addresses": ["bc1qdest", "bc1qchange", "bc1qother"],
+            "output_details": [
+                {{"output_type": "SCRIPT_TYPE_WITNESS_V0_PUBKEY_HASH", "address": "bc1qdest", "pk_script": "0014", "output_index": "0", "amount": "50000", "is_our_address": false}},
+                {{"output_type": "SCRIPT_TYPE_WITNESS_V0_PUBKEY_HASH", "address": "bc1qchange", "pk_script": "0014", "output_index": "1", "amount": "20000", "is_our_address": true}},
+                {{"output_type": "SCRIPT_TYPE_WITNESS_V0_PUBKEY_HASH", "address": "bc1qother", "pk_script": "0014", "output_index": "2", "amount": "800", "is_our_address": true}}
+            ],
+            "raw_tx_hex": "0200",
+            "label": "",
+            "previous_outpoints": []
+        }}"#
+    )
+}
+
+#[tokio::test]
+async fn sweeps_are_listed() {
+    let server = MockServer::start(vec![
+        route("GET", "/v2/wallet/sweeps/pending", PENDING_SWEEPS),
+        route(
+            "GET",
+            "/v2/wallet/sweeps",
+            r#"{"transaction_ids": {"transaction_ids": ["aaaa", "bbbb"]}}"#,
+        ),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let pending_sweeps = node.pending_sweeps().await.unwrap().pending_sweeps;
+    assert_eq!(
+        pending_sweeps[0].witness_type,
+        WitnessType::COMMITMENT_ANCHOR
+    );
+    assert_eq!(pending_sweeps[0].sat_per_vbyte, "12");
+    assert_eq!(pending_sweeps[1].witness_type, WitnessType::Unknown);
+
+    let list_sweeps_request = ListSweepsRequest {
+        verbose: false,
+        start_height: 800000,
+    };
+    match node.list_sweeps(&list_sweeps_request).await.unwrap() {
+        ListSweepsResponse::TransactionIds(ids) => {
+            assert_eq!(ids.transaction_ids, ["aaaa", "bbbb"])
+        }
+        response => panic!("unexpected response: {:?}", response),
+    }
+
+    assert_eq!(
+        server.requests()[1].path,
+        "/v2/wallet/sweeps?verbose=false&start_height=800000"
+    );
+}
+
+#[tokio::test]
+async fn fee_is_bumped() {
+    let server = MockServer::start(vec![route("POST", "/v2/wallet/bumpfee", "{}")]).await;
+    let node = node(&server).await;
+
+    let bump_fee_request = BumpFeeRequest {
+        outpoint: OutPoint {
+            txid_str: "aaaa".to_string(),
+            output_index: 1,
+            ..Default::default()
+        },
+        target_conf: Some(3),
+        ..Default::default()
+    };
+    node.bump_fee(&bump_fee_request).await.unwrap();
+
+    let body: serde_json::Value = serde_json::from_str(&server.requests()[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "outpoint": {"txid_bytes": "", "txid_str": "aaaa", "output_index": 1},
+            "target_conf": 3,
+            "force": false
+        })
+    );
+}
+
+#[tokio::test]
+async fn stuck_withdrawals_are_bumped_with_cpfp() {
+    let transactions = format!(
+        r#"{{"transactions": [{}, {}, {}]}}"#,
+        transaction("stuck", -50141, 0),
+        transaction("confirmed", -50141, 2),
+        transaction("incoming", 70800, 0),
+    );
+    let server = MockServer::start(vec![
+        route("GET", "/v1/getinfo", &get_info("alice", true)),
+        route("GET", "/v1/transactions", &transactions),
+        route("POST", "/v2/wallet/bumpfee", "{}"),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let stuck = StuckWithdrawal::find(&node, Duration::from_secs(3600))
+        .await
+        .unwrap();
+    assert_eq!(stuck.len(), 1);
+    assert_eq!(stuck[0].transaction.tx_hash, "stuck");
+    assert_eq!(stuck[0].outpoints().unwrap().len(), 2);
+
+    let spent = stuck[0].bump(&node, None, Some(25)).await.unwrap();
+    assert_eq!(spent.output_index, 1);
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[1].path,
+        "/v1/transactions?start_height=800000&end_height=-1"
+    );
+    let body: serde_json::Value = serde_json::from_str(&requests[2].body).unwrap();
+    assert_eq!(body["outpoint"]["txid_str"], "stuck");
+    assert_eq!(body["outpoint"]["output_index"], 1);
+    assert_eq!(body["sat_per_vbyte"], 25);
+
+    let mut incoming_only = stuck[0].clone();
+    incoming_only
+        .transaction
+        .output_details
+        .retain(|output| !output.is_our_address);
+    assert!(matches!(
+        incoming_only.bump(&node, None, Some(25)).await,
+        Err(NodeError::NoWalletOutput(tx_hash)) if tx_hash == "stuck"
+    ));
+    assert_eq!(server.requests().len(), 3);
+}
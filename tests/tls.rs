@@ -0,0 +1,158 @@
+#![cfg(feature = "rustls")]
+
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+use lnd_rest::node::{Node, NodeError, NodeOptions};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_rustls::rustls::{Certificate, PrivateKey, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+
+const CERT_FINGERPRINT: &str =
+    "60:AD:02:BC:1A:07:4C:61:FD:AE:AF:20:83:29:75:E3:09:FC:C1:3D:73:3F:0E:78:EF:E1:65:24:E8:48:D3:32";
+
+const INVOICE: &str = r#"{
+    "memo": "coffee",
+    "r_preimage": "",
+    "r_hash": "",
+    "value": "1",
+    "value_msat": "1000",
+    "settled": false,
+    "settle_date": "0",
+    "creation_date": "1690000000",
+    "payment_request": "lnbc10n1",
+    "expiry": "3600",
+    "state": "OPEN"
+}"#;
+
+fn tls_acceptor() -> TlsAcceptor {
+    let mut cert_reader = BufReader::new(File::open("tests/data/tls.cert").unwrap());
+    let certs = rustls_pemfile::certs(&mut cert_reader)
+        .unwrap()
+        .into_iter()
+        .map(Certificate)
+        .collect();
+
+    let mut key_reader = BufReader::new(File::open("tests/data/tls.key").unwrap());
+    let key = rustls_pemfile::pkcs8_private_keys(&mut key_reader)
+        .unwrap()
+        .remove(0);
+
+    let config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(certs, PrivateKey(key))
+        .unwrap();
+
+    TlsAcceptor::from(Arc::new(config))
+}
+
+/// Serve a single invoice lookup over TLS, ignoring handshake failures.
+async fn serve_once(listener: TcpListener) {
+    let acceptor = tls_acceptor();
+    let (stream, _) = listener.accept().await.unwrap();
+
+    let Ok(mut stream) = acceptor.accept(stream).await else {
+        return;
+    };
+
+    let mut data = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !data.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await.unwrap();
+        data.extend_from_slice(&buf[..n]);
+    }
+
+    let http_response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        INVOICE.len(),
+        INVOICE
+    );
+    stream.write_all(http_response.as_bytes()).await.unwrap();
+    stream.shutdown().await.unwrap();
+}
+
+async fn node_pinned_to(fingerprint: &str) -> (Node, tokio::task::JoinHandle<()>) {
+    // Listen on an address that does not appear in the certificate.
+    let listener = TcpListener::bind("127.0.0.2:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = tokio::spawn(serve_once(listener));
+
+    let options = NodeOptions {
+        cert_fingerprint: Some(fingerprint.to_string()),
+        ..Default::default()
+    };
+    let node = Node::init_with_options(
+        format!("https://127.0.0.2:{port}"),
+        "tests/data/admin.macaroon",
+        "tests/data/missing.cert",
+        &options,
+    )
+    .await
+    .unwrap();
+
+    (node, server)
+}
+
+#[tokio::test]
+async fn pinned_certificate_is_accepted() {
+    let (node, server) = node_pinned_to(CERT_FINGERPRINT).await;
+
+    let invoice = node.lookup_invoice(&"abcd".to_string()).await.unwrap();
+    assert_eq!(invoice.memo, "coffee");
+
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn other_certificate_is_rejected() {
+    let (node, server) = node_pinned_to(&"00".repeat(32)).await;
+
+    let err = node.lookup_invoice(&"abcd".to_string()).await.unwrap_err();
+    match err {
+        NodeError::CertFingerprintMismatch { expected, found } => {
+            assert_eq!(expected, ["00"; 32].join(":"));
+            assert_eq!(found, CERT_FINGERPRINT);
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+
+    server.await.unwrap();
+}
+
+#[cfg(feature = "websocket")]
+#[tokio::test]
+async fn other_certificate_is_rejected_over_websocket() {
+    let (node, server) = node_pinned_to(&"00".repeat(32)).await;
+
+    let err = node.htlc_interceptor().await.err().unwrap();
+    match err {
+        NodeError::CertFingerprintMismatch { expected, found } => {
+            assert_eq!(expected, ["00"; 32].join(":"));
+            assert_eq!(found, CERT_FINGERPRINT);
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn invalid_fingerprint_is_rejected() {
+    let options = NodeOptions {
+        cert_fingerprint: Some("not a fingerprint".to_string()),
+        ..Default::default()
+    };
+
+    let err = Node::init_with_options(
+        "https://127.0.0.1:8080".to_string(),
+        "tests/data/admin.macaroon",
+        "tests/data/missing.cert",
+        &options,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, NodeError::InvalidCertFingerprint(_)));
+}
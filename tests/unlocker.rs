@@ -0,0 +1,137 @@
+mod common;
+
+use common::{route, MockServer, Route, CERT_PATH, MACAROON_PATH};
+use lnd_rest::node::{Node, NodeError, NodeOptions};
+use lnd_rest::types::{GenSeedRequest, InitWalletRequest, UnlockWalletRequest, WalletState};
+
+const STATES: &str = concat!(
+    r#"{"result": {"state": "LOCKED"}}"#,
+    "\n",
+    r#"{"result": {"state": "UNLOCKED"}}"#,
+    "\n",
+    r#"{"result": {"state": "RPC_ACTIVE"}}"#,
+    "\n",
+    r#"{"result": {"state": "SERVER_ACTIVE"}}"#,
+    "\n",
+);
+
+#[tokio::test]
+async fn wallet_is_created_and_unlocked_without_macaroon() {
+    let server = MockServer::start(vec![
+        route("GET", "/v1/state", r#"{"state": "NON_EXISTING"}"#),
+        route(
+            "GET",
+            "/v1/genseed",
+            r#"{"cipher_seed_mnemonic": ["abandon", "ability"], "enciphered_seed": "AAE="}"#,
+        ),
+        route(
+            "POST",
+            "/v1/initwallet",
+            r#"{"admin_macaroon": "AgEDbG5k"}"#,
+        ),
+        route("POST", "/v1/unlockwallet", "{}"),
+        route("GET", "/v1/state/subscribe", STATES),
+    ])
+    .await;
+    let node = Node::init_without_macaroon(server.url.clone(), CERT_PATH, &NodeOptions::default())
+        .await
+        .unwrap();
+
+    let state = node.get_state().await.unwrap().state;
+    assert_eq!(state, WalletState::NON_EXISTING);
+
+    let gen_seed_request = GenSeedRequest {
+        aezeed_passphrase: Some("c2VjcmV0".to_string()),
+        ..Default::default()
+    };
+    let seed = node.gen_seed(&gen_seed_request).await.unwrap();
+
+    let init_wallet_request = InitWalletRequest {
+        wallet_password: "cGFzc3dvcmQ=".to_string(),
+        cipher_seed_mnemonic: seed.cipher_seed_mnemonic,
+        aezeed_passphrase: Some("c2VjcmV0".to_string()),
+        ..Default::default()
+    };
+    let wallet = node.init_wallet(&init_wallet_request).await.unwrap();
+    assert_eq!(wallet.admin_macaroon, "AgEDbG5k");
+
+    let unlock_wallet_request = UnlockWalletRequest {
+        wallet_password: "cGFzc3dvcmQ=".to_string(),
+        ..Default::default()
+    };
+    node.unlock_wallet(&unlock_wallet_request).await.unwrap();
+    node.wait_for_server_active().await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[1].path, "/v1/genseed?aezeed_passphrase=c2VjcmV0");
+    let body: serde_json::Value = serde_json::from_str(&requests[2].body).unwrap();
+    assert_eq!(body["cipher_seed_mnemonic"][1], "ability");
+    assert!(requests.iter().all(|request| request
+        .headers
+        .iter()
+        .all(|(name, _)| name != "grpc-metadata-macaroon")));
+}
+
+#[tokio::test]
+async fn error_bodies_are_returned_as_api_errors() {
+    let server = MockServer::start(vec![
+        Route {
+            method: "GET",
+            path: "/v1/getinfo".to_string(),
+            status: 500,
+            body: r#"{"code": 2, "message": "wallet locked, unlock it to enable full RPC access", "details": []}"#
+                .to_string(),
+        },
+        Route {
+            method: "GET",
+            path: "/v1/state".to_string(),
+            status: 502,
+            body: "Bad Gateway".to_string(),
+        },
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    match node.get_info().await {
+        Err(NodeError::ApiError { code, message }) => {
+            assert_eq!(code, 2);
+            assert!(message.starts_with("wallet locked"));
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert!(matches!(
+        node.get_state().await,
+        Err(NodeError::RequestError(_))
+    ));
+    assert!(server.requests()[0]
+        .headers
+        .iter()
+        .any(|(name, _)| name == "grpc-metadata-macaroon"));
+}
+
+#[tokio::test]
+async fn closed_and_empty_state_streams_are_reported() {
+    let server = MockServer::start(vec![
+        route(
+            "GET",
+            "/v1/state/subscribe",
+            concat!(r#"{"result": {"state": "LOCKED"}}"#, "\n"),
+        ),
+        route("GET", "/v1/state/subscribe", concat!("{}", "\n")),
+    ])
+    .await;
+    let node = Node::init(server.url.clone(), MACAROON_PATH, CERT_PATH)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        node.wait_for_server_active().await,
+        Err(NodeError::StreamClosed)
+    ));
+    assert!(matches!(
+        node.wait_for_server_active().await,
+        Err(NodeError::EmptyFrame)
+    ));
+}
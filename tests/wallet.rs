@@ -0,0 +1,348 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::{node, route, MockServer};
+use futures_util::StreamExt;
+use lnd_rest::types::{
+    AddressType, EstimateFeeRequest, FeeTier, FinalizePsbtRequest, FundPsbtRequest,
+    GetTransactionsRequest, LeaseOutputRequest, ListUnspentRequest, NewAddressRequest, OutPoint,
+    OutputScriptType, PublishTransactionRequest, ReleaseOutputRequest, SendCoinsRequest,
+    SendManyRequest, TxTemplate,
+};
+
+const UTXOS: &str = r#"{
+    "utxos": [{
+        "address_type": "TAPROOT_PUBKEY",
+        "address": "bc1p",
+        "amount_sat": "100000",
+        "pk_script": "5120",
+        "outpoint": {"txid_bytes": "", "txid_str": "aaaa", "output_index": 1},
+        "confirmations": "6"
+    }]
+}"#;
+
+const TRANSACTIONS: &str = r#"{
+    "transactions": [{
+        "tx_hash": "aaaa",
+        "amount": "-50000",
+        "num_confirmations": 3,
+        "block_hash": "0000",
+        "block_height": 800000,
+        "time_stamp": "1690000000",
+        "total_fees": "250",
+        "dest_addresses": ["bc1q"],
+        "output_details": [{
+            "output_type": "SCRIPT_TYPE_WITNESS_V0_PUBKEY_HASH",
+            "address": "bc1q",
+            "pk_script": "0014",
+            "output_index": "0",
+            "amount": "50000",
+            "is_our_address": false
+        }],
+        "raw_tx_hex": "0200",
+        "label": "withdrawal",
+        "previous_outpoints": [{"outpoint": "bbbb:0", "is_our_output": true}]
+    }]
+}"#;
+
+const LEASE: &str = r#"{
+    "id": "bG5kcmVzdA==",
+    "outpoint": {"txid_bytes": "qqo=", "txid_str": "aaaa", "output_index": 1},
+    "expiration": "1690000600",
+    "pk_script": "USA=",
+    "value": "100000"
+}"#;
+
+#[tokio::test]
+async fn wallet_queries_are_encoded() {
+    let server = MockServer::start(vec![
+        route("GET", "/v1/newaddress", r#"{"address": "bc1p"}"#),
+        route("GET", "/v1/utxos", UTXOS),
+        route("GET", "/v1/transactions", TRANSACTIONS),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let new_address_request = NewAddressRequest {
+        address_type: AddressType::TAPROOT_PUBKEY,
+        ..Default::default()
+    };
+    let address = node.new_address(&new_address_request).await.unwrap();
+    assert_eq!(address.address, "bc1p");
+
+    let list_unspent_request = ListUnspentRequest {
+        min_confs: 1,
+        max_confs: 100,
+        account: Some("default".to_string()),
+    };
+    let utxos = node
+        .list_unspent(&list_unspent_request)
+        .await
+        .unwrap()
+        .utxos;
+    assert_eq!(utxos[0].address_type, AddressType::TAPROOT_PUBKEY);
+    assert_eq!(utxos[0].outpoint.output_index, 1);
+
+    let get_transactions_request = GetTransactionsRequest {
+        start_height: 800000,
+        end_height: -1,
+        ..Default::default()
+    };
+    let transactions = node
+        .get_transactions(&get_transactions_request)
+        .await
+        .unwrap()
+        .transactions;
+    assert_eq!(
+        transactions[0].output_details[0].output_type,
+        OutputScriptType::SCRIPT_TYPE_WITNESS_V0_PUBKEY_HASH
+    );
+
+    let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+    assert_eq!(
+        paths,
+        [
+            "/v1/newaddress?type=TAPROOT_PUBKEY",
+            "/v1/utxos?min_confs=1&max_confs=100&account=default",
+            "/v1/transactions?start_height=800000&end_height=-1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn coins_are_sent() {
+    let server = MockServer::start(vec![
+        route("POST", "/v1/transactions", r#"{"txid": "aaaa"}"#),
+        route("POST", "/v1/transactions/many", r#"{"txid": "bbbb"}"#),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let send_coins_request = SendCoinsRequest {
+        addr: "bc1q".to_string(),
+        send_all: true,
+        sat_per_vbyte: Some(12),
+        label: Some("sweep".to_string()),
+        ..Default::default()
+    };
+    let sent = node.send_coins(&send_coins_request).await.unwrap();
+    assert_eq!(sent.txid, "aaaa");
+
+    let send_many_request = SendManyRequest {
+        addr_to_amount: HashMap::from([("bc1q".to_string(), 1000), ("bc1p".to_string(), 2000)]),
+        min_confs: Some(3),
+        ..Default::default()
+    };
+    let sent = node.send_many(&send_many_request).await.unwrap();
+    assert_eq!(sent.txid, "bbbb");
+
+    let requests = server.requests();
+    let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(body["send_all"], true);
+    assert_eq!(body["sat_per_vbyte"], 12);
+    let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+    assert_eq!(body["AddrToAmount"]["bc1p"], 2000);
+    assert_eq!(body["min_confs"], 3);
+}
+
+#[tokio::test]
+async fn fees_are_estimated() {
+    let server = MockServer::start(vec![
+        route(
+            "GET",
+            "/v1/transactions/fee",
+            r#"{"fee_sat": "1410", "feerate_sat_per_byte": "10", "sat_per_vbyte": "10"}"#,
+        ),
+        route(
+            "GET",
+            "/v2/wallet/estimatefee/2",
+            r#"{"sat_per_kw": "12500"}"#,
+        ),
+        route(
+            "GET",
+            "/v2/wallet/estimatefee/144",
+            r#"{"sat_per_kw": "253"}"#,
+        ),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let estimate_fee_request = EstimateFeeRequest {
+        addr_to_amount: HashMap::from([("bc1q".to_string(), 1000)]),
+        target_conf: 6,
+        ..Default::default()
+    };
+    let estimate = node.estimate_fee(&estimate_fee_request).await.unwrap();
+    assert_eq!(estimate.fee_sat, "1410");
+    assert_eq!(estimate.sat_per_vbyte, "10");
+
+    assert_eq!(node.fee_rate(FeeTier::Fast).await.unwrap(), 50);
+    assert_eq!(node.fee_rate(FeeTier::Economy).await.unwrap(), 2);
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/v1/transactions/fee?AddrToAmount%5Bbc1q%5D=1000&target_conf=6&min_confs=0&spend_unconfirmed=false"
+    );
+}
+
+#[tokio::test]
+async fn transactions_are_streamed() {
+    let transaction =
+        serde_json::from_str::<serde_json::Value>(TRANSACTIONS).unwrap()["transactions"][0].clone();
+    let events = format!(
+        "{}\n{}\n",
+        serde_json::json!({ "result": transaction }),
+        serde_json::json!({ "result": transaction })
+    );
+    let server = MockServer::start(vec![route("GET", "/v1/transactions/subscribe", &events)]).await;
+    let node = node(&server).await;
+
+    let transactions: Vec<_> = node
+        .subscribe_transactions(Some("default"))
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(transactions.len(), 2);
+    assert_eq!(transactions[0].as_ref().unwrap().num_confirmations, 3);
+
+    let _ = node.subscribe_transactions(None).await.unwrap();
+
+    let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+    assert_eq!(
+        paths,
+        [
+            "/v1/transactions/subscribe?account=default",
+            "/v1/transactions/subscribe"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn batched_payouts_are_funded_finalized_and_published() {
+    let server = MockServer::start(vec![
+        route(
+            "POST",
+            "/v2/wallet/psbt/fund",
+            &format!(
+                r#"{{"funded_psbt": "ZnVuZGVk", "change_output_index": 2, "locked_utxos": [{LEASE}]}}"#
+            ),
+        ),
+        route(
+            "POST",
+            "/v2/wallet/psbt/finalize",
+            r#"{"signed_psbt": "c2lnbmVk", "raw_final_tx": "AgAAAA=="}"#,
+        ),
+        route("POST", "/v2/wallet/tx", r#"{"publish_error": ""}"#),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let fund_psbt_request = FundPsbtRequest {
+        raw: Some(TxTemplate {
+            inputs: vec![OutPoint {
+                txid_str: "aaaa".to_string(),
+                output_index: 1,
+                ..Default::default()
+            }],
+            outputs: HashMap::from([("bc1q".to_string(), 40000), ("bc1p".to_string(), 50000)]),
+        }),
+        sat_per_vbyte: Some(8),
+        ..Default::default()
+    };
+    let funded = node.fund_psbt(&fund_psbt_request).await.unwrap();
+    assert_eq!(funded.change_output_index, 2);
+    assert_eq!(funded.locked_utxos[0].value, "100000");
+
+    let finalize_psbt_request = FinalizePsbtRequest {
+        funded_psbt: funded.funded_psbt,
+        ..Default::default()
+    };
+    let finalized = node.finalize_psbt(&finalize_psbt_request).await.unwrap();
+
+    let publish_transaction_request = PublishTransactionRequest {
+        tx_hex: finalized.raw_final_tx,
+        label: "payouts".to_string(),
+    };
+    let published = node
+        .publish_transaction(&publish_transaction_request)
+        .await
+        .unwrap();
+    assert_eq!(published.publish_error, "");
+
+    let bodies: Vec<serde_json::Value> = server
+        .requests()
+        .iter()
+        .map(|request| serde_json::from_str(&request.body).unwrap())
+        .collect();
+    assert_eq!(
+        bodies[0],
+        serde_json::json!({
+            "raw": {
+                "inputs": [{"txid_bytes": "", "txid_str": "aaaa", "output_index": 1}],
+                "outputs": {"bc1q": 40000, "bc1p": 50000}
+            },
+            "sat_per_vbyte": 8,
+            "account": "",
+            "min_confs": 0,
+            "spend_unconfirmed": false
+        })
+    );
+    assert_eq!(bodies[1]["funded_psbt"], "ZnVuZGVk");
+    assert_eq!(
+        bodies[2],
+        serde_json::json!({"tx_hex": "AgAAAA==", "label": "payouts"})
+    );
+}
+
+#[tokio::test]
+async fn outputs_are_leased_and_released() {
+    let server = MockServer::start(vec![
+        route(
+            "POST",
+            "/v2/wallet/utxos/lease",
+            r#"{"expiration": "1690000600"}"#,
+        ),
+        route(
+            "POST",
+            "/v2/wallet/utxos/leases",
+            &format!(r#"{{"locked_utxos": [{LEASE}]}}"#),
+        ),
+        route("POST", "/v2/wallet/utxos/release", r#"{}"#),
+    ])
+    .await;
+    let node = node(&server).await;
+
+    let outpoint = OutPoint {
+        txid_bytes: "qqo=".to_string(),
+        output_index: 1,
+        ..Default::default()
+    };
+
+    let lease_output_request = LeaseOutputRequest {
+        id: "bG5kcmVzdA==".to_string(),
+        outpoint: outpoint.clone(),
+        expiration_seconds: 600,
+    };
+    let lease = node.lease_output(&lease_output_request).await.unwrap();
+    assert_eq!(lease.expiration, "1690000600");
+
+    let leases = node.list_leases().await.unwrap().locked_utxos;
+    assert_eq!(leases[0].outpoint.txid_str, "aaaa");
+
+    let release_output_request = ReleaseOutputRequest {
+        id: "bG5kcmVzdA==".to_string(),
+        outpoint,
+    };
+    node.release_output(&release_output_request).await.unwrap();
+
+    let requests = server.requests();
+    let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+    assert_eq!(body["expiration_seconds"], 600);
+    assert_eq!(body["outpoint"]["txid_bytes"], "qqo=");
+    assert_eq!(requests[1].body, "{}");
+    let body: serde_json::Value = serde_json::from_str(&requests[2].body).unwrap();
+    assert_eq!(body["id"], "bG5kcmVzdA==");
+}